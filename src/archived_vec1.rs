@@ -0,0 +1,192 @@
+//! rkyv support, the archived form of `Vec1` (`ArchivedVec1`).
+//!
+//! `Vec1<T>` is archived as `ArchivedVec1<T::Archived>` which has the same
+//! layout as `rkyv::vec::ArchivedVec`. Validating an archive (e.g. with
+//! `rkyv::check_archived_root`) fails if the vector is empty, so the non-empty
+//! invariant also holds for zero-copy deserialization.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::vec1;
+//!
+//! let bytes = rkyv::to_bytes::<_, 256>(&vec1![1u32, 2, 3]).unwrap();
+//! let archived = rkyv::check_archived_root::<vec1::Vec1<u32>>(&bytes).unwrap();
+//! assert_eq!(archived.last(), &3);
+//! ```
+
+use core::{fmt, num::NonZeroUsize, ops::Deref};
+
+use alloc::{boxed::Box, vec::Vec};
+
+use rkyv::{
+    bytecheck::{CheckBytes, NonZeroCheckError, StructCheckError},
+    ser::{ScratchSpace, Serializer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, DeserializeUnsized, Fallible, Serialize,
+};
+
+use crate::{Slice1, Vec1};
+
+/// The archived form of a `Vec1`, a non-empty `ArchivedVec`.
+#[repr(transparent)]
+pub struct ArchivedVec1<T>(ArchivedVec<T>);
+
+impl<T> ArchivedVec1<T> {
+    /// Returns the elements as slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    /// Returns the elements as a non-empty `Slice1`.
+    pub fn as_slice1(&self) -> &Slice1<T> {
+        // SAFETY: only a layout cast, the archive was created from a `Vec1` or validated
+        //         to not be empty (and an empty one could only cause panics)
+        unsafe { Slice1::from_slice_unchecked(self.0.as_slice()) }
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        self.as_slice1().len_nonzero()
+    }
+
+    /// Returns a reference to the first element.
+    pub fn first(&self) -> &T {
+        self.as_slice1().first()
+    }
+
+    /// Returns a reference to the last element.
+    pub fn last(&self) -> &T {
+        self.as_slice1().last()
+    }
+}
+
+impl<T> Deref for ArchivedVec1<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0.as_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for ArchivedVec1<T> {
+    fn as_ref(&self) -> &[T] {
+        self.0.as_slice()
+    }
+}
+
+impl<T> fmt::Debug for ArchivedVec1<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), fter)
+    }
+}
+
+impl<A, B> PartialEq<[B]> for ArchivedVec1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<A, B, const N: usize> PartialEq<[B; N]> for ArchivedVec1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T> Archive for Vec1<T>
+where
+    T: Archive,
+{
+    type Archived = ArchivedVec1<T::Archived>;
+    type Resolver = VecResolver;
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        // SAFETY: `ArchivedVec1` is `repr(transparent)` over `ArchivedVec`
+        unsafe { ArchivedVec::resolve_from_slice(self.as_slice(), pos, resolver, out.cast()) }
+    }
+}
+
+impl<T, S> Serialize<S> for Vec1<T>
+where
+    T: Serialize<S>,
+    S: ScratchSpace + Serializer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<T, D> Deserialize<Vec1<T>, D> for ArchivedVec1<T::Archived>
+where
+    T: Archive,
+    D: Fallible + ?Sized,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Vec1<T>, D::Error> {
+        let vec: Vec<T> = self.0.deserialize(deserializer)?;
+        //UNWRAP_SAFE: the archive was created from a `Vec1` or validated to not be empty
+        Ok(Vec1::try_from_vec(vec).unwrap())
+    }
+}
+
+impl<T, C> CheckBytes<C> for ArchivedVec1<T>
+where
+    ArchivedVec<T>: CheckBytes<C>,
+    C: ?Sized,
+{
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        // SAFETY: `ArchivedVec1` is `repr(transparent)` over `ArchivedVec`
+        let vec =
+            unsafe { ArchivedVec::<T>::check_bytes(value.cast(), context) }.map_err(|err| {
+                StructCheckError {
+                    field_name: "0",
+                    inner: Box::new(err),
+                }
+            })?;
+        if vec.is_empty() {
+            Err(StructCheckError {
+                field_name: "0",
+                inner: Box::new(NonZeroCheckError::IsZero),
+            })
+        } else {
+            // SAFETY: checked above
+            Ok(unsafe { &*value })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn roundtrip() {
+        let bytes = rkyv::to_bytes::<_, 256>(&vec1![1u16, 2, 3]).unwrap();
+        let archived = rkyv::check_archived_root::<Vec1<u16>>(&bytes).unwrap();
+        assert_eq!(archived, &[1u16, 2, 3]);
+        assert_eq!(archived.len_nonzero().get(), 3);
+        let vec: Vec1<u16> = archived.deserialize(&mut rkyv::Infallible).unwrap();
+        assert_eq!(vec, vec1![1u16, 2, 3]);
+    }
+
+    #[test]
+    fn empty_is_rejected() {
+        let bytes = rkyv::to_bytes::<_, 256>(&Vec::<u16>::new()).unwrap();
+        assert!(rkyv::check_archived_root::<Vec1<u16>>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,595 @@
+//! A non-empty ordered map type (`BTreeMap1`) and it's entry API.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::BTreeMap1;
+//!
+//! let mut versions = BTreeMap1::new(2, "two");
+//! versions.insert(1, "one");
+//! versions.insert(3, "three");
+//! assert_eq!(versions.first_key_value(), (&1, &"one"));
+//! assert_eq!(versions.pop_last(), Ok((3, "three")));
+//! assert_eq!(versions.range(2..).count(), 1);
+//! assert_eq!(versions.pop_first(), Ok((1, "one")));
+//! // popping the last entry fails
+//! assert!(versions.pop_first().is_err());
+//! ```
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    num::NonZeroUsize,
+    ops::{Deref, Index, RangeBounds},
+};
+
+use alloc::collections::{btree_map, BTreeMap};
+
+use crate::{retain_keep_last, Size0Error, Vec1};
+
+/// `BTreeMap` wrapper which guarantees to have at least 1 entry.
+///
+/// Dereferences to `&BTreeMap<K, V>`, methods which could make the map
+/// empty return a `Result`. Methods like `first_key_value` are shadowed
+/// by methods which don't return a `Option`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BTreeMap1<K, V>(BTreeMap<K, V>);
+
+impl<K, V> BTreeMap1<K, V>
+where
+    K: Ord,
+{
+    /// Creates a new `BTreeMap1` containing just the given entry.
+    pub fn new(key: K, value: V) -> Self {
+        let mut map = BTreeMap::new();
+        map.insert(key, value);
+        BTreeMap1(map)
+    }
+
+    /// Tries to create a `BTreeMap1` from a `BTreeMap`.
+    ///
+    /// # Errors
+    ///
+    /// If the map is empty.
+    pub fn try_from_map(map: BTreeMap<K, V>) -> Result<Self, Size0Error> {
+        if map.is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(BTreeMap1(map))
+        }
+    }
+
+    /// Returns the underlying `BTreeMap`.
+    pub fn into_map(self) -> BTreeMap<K, V> {
+        self.0
+    }
+
+    /// Returns a reference to the underlying `BTreeMap`.
+    pub fn as_map(&self) -> &BTreeMap<K, V> {
+        &self.0
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn first_key_value(&self) -> (&K, &V) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.first_key_value().unwrap()
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn last_key_value(&self) -> (&K, &V) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.last_key_value().unwrap()
+    }
+
+    /// Inserts a key-value pair into the map, see [`BTreeMap::insert()`].
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Returns an iterator over all entries with mutable references to the values.
+    pub fn iter_mut(&mut self) -> btree_map::IterMut<'_, K, V> {
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator over mutable references to all values.
+    pub fn values_mut(&mut self) -> btree_map::ValuesMut<'_, K, V> {
+        self.0.values_mut()
+    }
+
+    /// Returns an iterator over the entries in `range` with mutable references to the values.
+    ///
+    /// # Panics
+    ///
+    /// Like [`BTreeMap::range_mut()`].
+    pub fn range_mut<Q, R>(&mut self, range: R) -> btree_map::RangeMut<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        self.0.range_mut(range)
+    }
+
+    /// Gets the entry for the given key for in-place manipulation.
+    ///
+    /// Unlike [`BTreeMap::entry()`] removing the entry fails if it's the last entry.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let is_last = self.0.len() == 1;
+        match self.0.entry(key) {
+            btree_map::Entry::Occupied(entry) => Entry::Occupied(OccupiedEntry { entry, is_last }),
+            btree_map::Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+
+    /// Removes a key from the map returning the value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If the key is the only key in the map an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, Size0Error>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key)
+            .map(|entry| entry.map(|(_, value)| value))
+    }
+
+    /// Removes a key from the map returning the stored key and value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If the key is the only key in the map an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Result<Option<(K, V)>, Size0Error>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.0.len() > 1 {
+            Ok(self.0.remove_entry(key))
+        } else if self.0.contains_key(key) {
+            Err(Size0Error)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Removes and returns the entry with the smallest key, if there is more than one entry.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn pop_first(&mut self) -> Result<(K, V), Size0Error> {
+        if self.0.len() > 1 {
+            //UNWRAP_SAFE: pop on len > 1 can not be none
+            Ok(self.0.pop_first().unwrap())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Removes and returns the entry with the largest key, if there is more than one entry.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn pop_last(&mut self) -> Result<(K, V), Size0Error> {
+        if self.0.len() > 1 {
+            //UNWRAP_SAFE: pop on len > 1 can not be none
+            Ok(self.0.pop_last().unwrap())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Removes all entries except the ones which the predicate says need to be retained.
+    ///
+    /// Like [`Vec1::retain()`] this fails the moment the last entry would be removed,
+    /// not removing that entry. **All other non retained entries will still be removed.**
+    /// As entries are visited in ascending key order the entry with the largest key is the
+    /// one kept.
+    ///
+    /// # Errors
+    ///
+    /// If the last entry would be removed instead of removing it a `Size0Error` is
+    /// returned.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), Size0Error>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut kept_any = false;
+        let mut keep = retain_keep_last(self.0.len(), &mut kept_any);
+        self.0.retain(move |key, value| keep(f(key, value)));
+        if kept_any {
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
+    }
+}
+
+/// A view into a single entry of a [`BTreeMap1`], see [`BTreeMap1::entry()`].
+pub enum Entry<'a, K, V> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// A vacant entry, as it can only be inserted into the std type is used.
+    Vacant(btree_map::VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V>
+where
+    K: Ord,
+{
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant and returns a mutable reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if the entry is vacant and returns a mutable
+    /// reference to the value.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts `V::default()` if the entry is vacant and returns a mutable reference to the value.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(V::default()),
+        }
+    }
+
+    /// Calls `f` with the value if the entry is occupied.
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+/// A view into an occupied entry of a [`BTreeMap1`].
+pub struct OccupiedEntry<'a, K, V> {
+    entry: btree_map::OccupiedEntry<'a, K, V>,
+    is_last: bool,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V>
+where
+    K: Ord,
+{
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        self.entry.key()
+    }
+
+    /// Returns a reference to the value.
+    pub fn get(&self) -> &V {
+        self.entry.get()
+    }
+
+    /// Returns a mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.entry.get_mut()
+    }
+
+    /// Converts the entry into a mutable reference to the value.
+    pub fn into_mut(self) -> &'a mut V {
+        self.entry.into_mut()
+    }
+
+    /// Replaces the value returning the old value.
+    pub fn insert(&mut self, value: V) -> V {
+        self.entry.insert(value)
+    }
+
+    /// Removes the entry returning the value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If it's the last entry an error is returned **without** removing it.
+    pub fn remove(self) -> Result<V, Size0Error> {
+        self.remove_entry().map(|(_, value)| value)
+    }
+
+    /// Removes the entry returning the key and value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If it's the last entry an error is returned **without** removing it.
+    pub fn remove_entry(self) -> Result<(K, V), Size0Error> {
+        if self.is_last {
+            Err(Size0Error)
+        } else {
+            Ok(self.entry.remove_entry())
+        }
+    }
+}
+
+impl<K, V> Deref for BTreeMap1<K, V> {
+    type Target = BTreeMap<K, V>;
+
+    fn deref(&self) -> &BTreeMap<K, V> {
+        &self.0
+    }
+}
+
+impl<K, V> AsRef<BTreeMap<K, V>> for BTreeMap1<K, V> {
+    fn as_ref(&self) -> &BTreeMap<K, V> {
+        &self.0
+    }
+}
+
+impl<K, Q, V> Index<&Q> for BTreeMap1<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        &self.0[key]
+    }
+}
+
+impl<K, V> TryFrom<BTreeMap<K, V>> for BTreeMap1<K, V>
+where
+    K: Ord,
+{
+    type Error = Size0Error;
+
+    fn try_from(map: BTreeMap<K, V>) -> Result<Self, Size0Error> {
+        BTreeMap1::try_from_map(map)
+    }
+}
+
+impl<K, V> From<BTreeMap1<K, V>> for BTreeMap<K, V> {
+    fn from(map: BTreeMap1<K, V>) -> Self {
+        map.0
+    }
+}
+
+impl<K, V> From<Vec1<(K, V)>> for BTreeMap1<K, V>
+where
+    K: Ord,
+{
+    /// If a key appears multiple times the last value is used.
+    fn from(vec: Vec1<(K, V)>) -> Self {
+        BTreeMap1(vec.into_iter().collect())
+    }
+}
+
+impl<K, V> From<BTreeMap1<K, V>> for Vec1<(K, V)> {
+    /// The entries are in ascending key order.
+    fn from(map: BTreeMap1<K, V>) -> Self {
+        //UNWRAP_SAFE: len is at least 1
+        Vec1::try_from_vec(map.0.into_iter().collect()).unwrap()
+    }
+}
+
+impl<K, V> Extend<(K, V)> for BTreeMap1<K, V>
+where
+    K: Ord,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<K, V> IntoIterator for BTreeMap1<K, V> {
+    type Item = (K, V);
+    type IntoIter = btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a BTreeMap1<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = btree_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut BTreeMap1<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = btree_map::IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<K, V> fmt::Debug for BTreeMap1<K, V>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<K, V> PartialEq<BTreeMap<K, V>> for BTreeMap1<K, V>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    fn eq(&self, other: &BTreeMap<K, V>) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    use core::marker::PhantomData;
+    use serde::{
+        de::{Deserialize, Deserializer, Error as _, MapAccess, Visitor},
+        ser::{Serialize, SerializeMap, Serializer},
+    };
+
+    impl<K, V> Serialize for BTreeMap1<K, V>
+    where
+        K: Serialize,
+        V: Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map_ser = serializer.serialize_map(Some(self.len()))?;
+            for (key, value) in self.iter() {
+                map_ser.serialize_entry(key, value)?;
+            }
+            map_ser.end()
+        }
+    }
+
+    impl<'de, K, V> Deserialize<'de> for BTreeMap1<K, V>
+    where
+        K: Deserialize<'de> + Ord,
+        V: Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(MapVisitor {
+                _type_carry: PhantomData,
+            })
+        }
+    }
+
+    struct MapVisitor<K, V> {
+        _type_carry: PhantomData<BTreeMap<K, V>>,
+    }
+
+    impl<'de, K, V> Visitor<'de> for MapVisitor<K, V>
+    where
+        K: Deserialize<'de> + Ord,
+        V: Deserialize<'de>,
+    {
+        type Value = BTreeMap1<K, V>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map = BTreeMap::new();
+            while let Some((key, value)) = access.next_entry()? {
+                map.insert(key, value);
+            }
+            BTreeMap1::try_from_map(map).map_err(A::Error::custom)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn try_from_map() {
+        assert_eq!(
+            BTreeMap1::try_from(BTreeMap::from([(1u8, 'a')])),
+            Ok(BTreeMap1::new(1u8, 'a'))
+        );
+        assert_eq!(
+            BTreeMap1::<u8, char>::try_from_map(BTreeMap::new()),
+            Err(Size0Error)
+        );
+    }
+
+    #[test]
+    fn remove_and_pop() {
+        let mut map = BTreeMap1::from(vec1![(3u8, 'c'), (1, 'a'), (2, 'b'), (4, 'd')]);
+        assert_eq!(map.first_key_value(), (&1, &'a'));
+        assert_eq!(map.last_key_value(), (&4, &'d'));
+        assert_eq!(map.remove(&5), Ok(None));
+        assert_eq!(map.remove(&2), Ok(Some('b')));
+        assert_eq!(map.pop_first(), Ok((1, 'a')));
+        assert_eq!(map.pop_last(), Ok((4, 'd')));
+        assert_eq!(map.pop_last(), Err(Size0Error));
+        assert_eq!(map.remove_entry(&3), Err(Size0Error));
+    }
+
+    #[test]
+    fn retain_and_range() {
+        let mut map = BTreeMap1::from(vec1![(1u8, 1u8), (2, 2), (3, 3)]);
+        for (_, value) in map.range_mut(2..) {
+            *value *= 10;
+        }
+        assert_eq!(map.retain(|k, _| k % 2 == 1), Ok(()));
+        assert_eq!(map, BTreeMap::from([(1, 1), (3, 30)]));
+        assert_eq!(map.retain(|_, _| false), Err(Size0Error));
+        assert_eq!(Vec1::from(map), vec1![(3u8, 30u8)]);
+    }
+
+    #[test]
+    fn entry() {
+        let mut map = BTreeMap1::new(1u8, 10u8);
+        *map.entry(2).or_default() += 2;
+        map.entry(1).and_modify(|v| *v += 1).or_insert(0);
+        assert_eq!(map, BTreeMap::from([(1, 11), (2, 2)]));
+        match map.entry(1) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), Ok(11)),
+            Entry::Vacant(_) => panic!("entry should be occupied"),
+        }
+        match map.entry(2) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), Err(Size0Error)),
+            Entry::Vacant(_) => panic!("entry should be occupied"),
+        }
+        assert_eq!(map[&2], 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let map: BTreeMap1<u8, u8> = serde_json::from_str(r#"{"1": 2, "0": 1}"#).unwrap();
+        assert_eq!(map.first_key_value(), (&0, &1));
+        assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"0":1,"1":2}"#);
+        let res: Result<BTreeMap1<u8, u8>, _> = serde_json::from_str("{}");
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,315 @@
+//! A non-empty ordered set type (`BTreeSet1`).
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::{vec1, BTreeSet1};
+//!
+//! let mut set = BTreeSet1::from(vec1![3, 1, 2, 1]);
+//! assert_eq!(set.first(), &1);
+//! assert_eq!(set.last(), &3);
+//! assert_eq!(set.pop_last(), Ok(3));
+//! assert_eq!(set.pop_first(), Ok(1));
+//! // popping the last element fails
+//! assert!(set.pop_first().is_err());
+//! ```
+
+use core::{borrow::Borrow, fmt, num::NonZeroUsize, ops::Deref};
+
+use alloc::collections::{btree_set, BTreeSet};
+
+use crate::{retain_keep_last, Size0Error, Vec1};
+
+/// `BTreeSet` wrapper which guarantees to have at least 1 element.
+///
+/// Dereferences to `&BTreeSet<T>`, methods which could make the set
+/// empty return a `Result`. Methods like `first` and `last` are shadowed
+/// by methods which don't return a `Option`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BTreeSet1<T>(BTreeSet<T>);
+
+impl<T> BTreeSet1<T>
+where
+    T: Ord,
+{
+    /// Creates a new `BTreeSet1` containing just `first`.
+    pub fn new(first: T) -> Self {
+        let mut set = BTreeSet::new();
+        set.insert(first);
+        BTreeSet1(set)
+    }
+
+    /// Tries to create a `BTreeSet1` from a `BTreeSet`.
+    ///
+    /// # Errors
+    ///
+    /// If the set is empty.
+    pub fn try_from_set(set: BTreeSet<T>) -> Result<Self, Size0Error> {
+        if set.is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(BTreeSet1(set))
+        }
+    }
+
+    /// Returns the underlying `BTreeSet`.
+    pub fn into_set(self) -> BTreeSet<T> {
+        self.0
+    }
+
+    /// Returns a reference to the underlying `BTreeSet`.
+    pub fn as_set(&self) -> &BTreeSet<T> {
+        &self.0
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns the smallest element.
+    pub fn first(&self) -> &T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.first().unwrap()
+    }
+
+    /// Returns the largest element.
+    pub fn last(&self) -> &T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.last().unwrap()
+    }
+
+    /// Adds a value to the set, see [`BTreeSet::insert()`].
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Adds a value to the set, replacing the existing equal value, see [`BTreeSet::replace()`].
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        self.0.replace(value)
+    }
+
+    /// Removes a value from the set, if it isn't the last element.
+    ///
+    /// Returns whether the value was present in the set.
+    ///
+    /// # Errors
+    ///
+    /// If the value is the only element in the set an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, Size0Error>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.take(value).map(|taken| taken.is_some())
+    }
+
+    /// Removes and returns the value equal to the given one, if it isn't the last element.
+    ///
+    /// # Errors
+    ///
+    /// If the value is the only element in the set an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn take<Q>(&mut self, value: &Q) -> Result<Option<T>, Size0Error>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.0.len() > 1 {
+            Ok(self.0.take(value))
+        } else if self.0.contains(value) {
+            Err(Size0Error)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Removes and returns the smallest element, if there is more than one element.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn pop_first(&mut self) -> Result<T, Size0Error> {
+        if self.0.len() > 1 {
+            //UNWRAP_SAFE: pop on len > 1 can not be none
+            Ok(self.0.pop_first().unwrap())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Removes and returns the largest element, if there is more than one element.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn pop_last(&mut self) -> Result<T, Size0Error> {
+        if self.0.len() > 1 {
+            //UNWRAP_SAFE: pop on len > 1 can not be none
+            Ok(self.0.pop_last().unwrap())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Removes all elements except the ones which the predicate says need to be retained.
+    ///
+    /// Like [`Vec1::retain()`] this fails the moment the last element would be removed,
+    /// not removing that element. **All other non retained elements will still be removed.**
+    /// As elements are visited in ascending order the largest element is the one kept.
+    ///
+    /// # Errors
+    ///
+    /// If the last element would be removed instead of removing it a `Size0Error` is
+    /// returned.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), Size0Error>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut kept_any = false;
+        let mut keep = retain_keep_last(self.0.len(), &mut kept_any);
+        self.0.retain(move |value| keep(f(value)));
+        if kept_any {
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
+    }
+}
+
+impl<T> Deref for BTreeSet1<T> {
+    type Target = BTreeSet<T>;
+
+    fn deref(&self) -> &BTreeSet<T> {
+        &self.0
+    }
+}
+
+impl<T> AsRef<BTreeSet<T>> for BTreeSet1<T> {
+    fn as_ref(&self) -> &BTreeSet<T> {
+        &self.0
+    }
+}
+
+impl<T> TryFrom<BTreeSet<T>> for BTreeSet1<T>
+where
+    T: Ord,
+{
+    type Error = Size0Error;
+
+    fn try_from(set: BTreeSet<T>) -> Result<Self, Size0Error> {
+        BTreeSet1::try_from_set(set)
+    }
+}
+
+impl<T> From<BTreeSet1<T>> for BTreeSet<T> {
+    fn from(set: BTreeSet1<T>) -> Self {
+        set.0
+    }
+}
+
+impl<T> From<Vec1<T>> for BTreeSet1<T>
+where
+    T: Ord,
+{
+    fn from(vec: Vec1<T>) -> Self {
+        BTreeSet1(vec.into_iter().collect())
+    }
+}
+
+impl<T> From<BTreeSet1<T>> for Vec1<T> {
+    /// The elements are in ascending order.
+    fn from(set: BTreeSet1<T>) -> Self {
+        //UNWRAP_SAFE: len is at least 1
+        Vec1::try_from_vec(set.0.into_iter().collect()).unwrap()
+    }
+}
+
+impl<T> Extend<T> for BTreeSet1<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> IntoIterator for BTreeSet1<T> {
+    type Item = T;
+    type IntoIter = btree_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BTreeSet1<T> {
+    type Item = &'a T;
+    type IntoIter = btree_set::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T> fmt::Debug for BTreeSet1<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<T> PartialEq<BTreeSet<T>> for BTreeSet1<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &BTreeSet<T>) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn try_from_set() {
+        assert_eq!(
+            BTreeSet1::try_from(BTreeSet::from([1u8])),
+            Ok(BTreeSet1::new(1u8))
+        );
+        assert_eq!(
+            BTreeSet1::<u8>::try_from_set(BTreeSet::new()),
+            Err(Size0Error)
+        );
+    }
+
+    #[test]
+    fn remove_and_pop() {
+        let mut set = BTreeSet1::from(vec1![4u8, 1, 2, 3]);
+        assert_eq!((set.first(), set.last()), (&1, &4));
+        assert_eq!(set.remove(&5), Ok(false));
+        assert_eq!(set.remove(&2), Ok(true));
+        assert_eq!(set.pop_first(), Ok(1));
+        assert_eq!(set.pop_last(), Ok(4));
+        assert_eq!(set.pop_last(), Err(Size0Error));
+        assert_eq!(set.remove(&3), Err(Size0Error));
+        assert_eq!(set.take(&3), Err(Size0Error));
+    }
+
+    #[test]
+    fn retain() {
+        let mut set = BTreeSet1::from(vec1![1u8, 2, 3, 4]);
+        assert_eq!(set.retain(|v| v % 2 == 1), Ok(()));
+        assert_eq!(set, BTreeSet::from([1, 3]));
+        assert_eq!(set.retain(|_| false), Err(Size0Error));
+        assert_eq!(Vec1::from(set), vec1![3u8]);
+    }
+}
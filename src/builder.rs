@@ -0,0 +1,141 @@
+//! Incremental construction of a `Vec1` (`Vec1Builder`).
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::{Size0Error, Vec1Builder};
+//!
+//! let mut builder = Vec1Builder::new();
+//! for line in "a\n\nb".lines() {
+//!     if !line.is_empty() {
+//!         builder.push(line);
+//!     }
+//! }
+//! assert_eq!(builder.finish(), Ok(vec1::vec1!["a", "b"]));
+//! assert_eq!(Vec1Builder::<u8>::new().finish(), Err(Size0Error));
+//! ```
+
+use core::fmt;
+
+use alloc::vec::Vec;
+
+use crate::{Size0Error, Vec1};
+
+/// Builder which collects elements and turns them into a `Vec1` once done.
+///
+/// Unlike a `Vec1` the builder starts out empty, so it can be used in
+/// loops where it's not known upfront if there will be any elements.
+/// Whether it's empty is only checked by [`Vec1Builder::finish()`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Vec1Builder<T>(Vec<T>);
+
+impl<T> Vec1Builder<T> {
+    /// Creates a new, empty builder.
+    pub fn new() -> Self {
+        Vec1Builder(Vec::new())
+    }
+
+    /// Creates a new, empty builder with space for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Vec1Builder(Vec::with_capacity(capacity))
+    }
+
+    /// Appends an element to the back.
+    pub fn push(&mut self, value: T) {
+        self.0.push(value)
+    }
+
+    /// Returns the number of elements collected so far.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no element was collected so far.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the elements collected so far as slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Turns the builder into a `Vec1`.
+    ///
+    /// # Errors
+    ///
+    /// If no element was collected.
+    pub fn finish(self) -> Result<Vec1<T>, Size0Error> {
+        Vec1::try_from_vec(self.0)
+    }
+}
+
+impl<T> Default for Vec1Builder<T> {
+    fn default() -> Self {
+        Vec1Builder::new()
+    }
+}
+
+impl<T> Extend<T> for Vec1Builder<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a, T> Extend<&'a T> for Vec1Builder<T>
+where
+    T: Copy + 'a,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> FromIterator<T> for Vec1Builder<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vec1Builder(Vec::from_iter(iter))
+    }
+}
+
+impl<T> From<Vec1<T>> for Vec1Builder<T> {
+    fn from(vec: Vec1<T>) -> Self {
+        Vec1Builder(vec.into_vec())
+    }
+}
+
+impl<T> fmt::Debug for Vec1Builder<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fter.debug_tuple("Vec1Builder").field(&self.0).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn finish() {
+        let mut builder = Vec1Builder::with_capacity(4);
+        assert!(builder.is_empty());
+        builder.push(1u8);
+        builder.extend([2, 3]);
+        builder.extend(&[4]);
+        assert_eq!(builder.len(), 4);
+        assert_eq!(builder.as_slice(), [1, 2, 3, 4]);
+        assert_eq!(builder.finish(), Ok(vec1![1u8, 2, 3, 4]));
+        assert_eq!(Vec1Builder::<u8>::default().finish(), Err(Size0Error));
+    }
+
+    #[test]
+    fn from_iter_and_vec1() {
+        let builder: Vec1Builder<u8> = (0..0).collect();
+        assert_eq!(builder.finish(), Err(Size0Error));
+        let mut builder = Vec1Builder::from(vec1![1u8]);
+        builder.push(2);
+        assert_eq!(builder.finish(), Ok(vec1![1u8, 2]));
+    }
+}
@@ -0,0 +1,245 @@
+//! A non-empty clone-on-write slice (`Cow1`).
+//!
+//! # Example
+//!
+//! ```
+//! use std::borrow::Cow;
+//! use vec1::{vec1, Cow1};
+//!
+//! let data = [1, 2];
+//! let mut cow = Cow1::try_from(Cow::Borrowed(&data[..])).unwrap();
+//! assert!(cow.is_borrowed());
+//! cow.to_mut().push(3);
+//! assert_eq!(cow.into_owned(), vec1![1, 2, 3]);
+//! ```
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use alloc::borrow::Cow;
+
+use crate::{Size0Error, Slice1, Vec1};
+
+/// Clone-on-write non-empty slice, either borrowing a `Slice1` or owning a `Vec1`.
+///
+/// Works like `Cow<'a, [T]>` but keeps the length >= 1 guarantee.
+pub enum Cow1<'a, T>
+where
+    T: Clone,
+{
+    /// Borrowed non-empty slice.
+    Borrowed(&'a Slice1<T>),
+    /// Owned non-empty vector.
+    Owned(Vec1<T>),
+}
+
+impl<'a, T> Cow1<'a, T>
+where
+    T: Clone,
+{
+    /// Returns `true` if the data is borrowed.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Cow1::Borrowed(_))
+    }
+
+    /// Returns `true` if the data is owned.
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    /// Returns the data as `Slice1`.
+    pub fn as_slice1(&self) -> &Slice1<T> {
+        match self {
+            Cow1::Borrowed(slice) => slice,
+            Cow1::Owned(vec) => vec.as_slice1(),
+        }
+    }
+
+    /// Returns a mutable reference to the owned data, cloning the data if it's borrowed.
+    pub fn to_mut(&mut self) -> &mut Vec1<T> {
+        match self {
+            Cow1::Owned(vec) => vec,
+            Cow1::Borrowed(slice) => {
+                *self = Cow1::Owned(slice.to_vec1());
+                self.to_mut()
+            }
+        }
+    }
+
+    /// Returns the owned data, cloning the data if it's borrowed.
+    pub fn into_owned(self) -> Vec1<T> {
+        match self {
+            Cow1::Borrowed(slice) => slice.to_vec1(),
+            Cow1::Owned(vec) => vec,
+        }
+    }
+}
+
+impl<'a, T> Deref for Cow1<'a, T>
+where
+    T: Clone,
+{
+    type Target = Slice1<T>;
+
+    fn deref(&self) -> &Slice1<T> {
+        self.as_slice1()
+    }
+}
+
+impl<'a, T> AsRef<[T]> for Cow1<'a, T>
+where
+    T: Clone,
+{
+    fn as_ref(&self) -> &[T] {
+        self.as_slice1()
+    }
+}
+
+impl<'a, T> Clone for Cow1<'a, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Cow1::Borrowed(slice) => Cow1::Borrowed(slice),
+            Cow1::Owned(vec) => Cow1::Owned(vec.clone()),
+        }
+    }
+}
+
+impl<'a, T> From<&'a Slice1<T>> for Cow1<'a, T>
+where
+    T: Clone,
+{
+    fn from(slice: &'a Slice1<T>) -> Self {
+        Cow1::Borrowed(slice)
+    }
+}
+
+impl<'a, T> From<&'a Vec1<T>> for Cow1<'a, T>
+where
+    T: Clone,
+{
+    fn from(vec: &'a Vec1<T>) -> Self {
+        Cow1::Borrowed(vec.as_slice1())
+    }
+}
+
+impl<'a, T> From<Vec1<T>> for Cow1<'a, T>
+where
+    T: Clone,
+{
+    fn from(vec: Vec1<T>) -> Self {
+        Cow1::Owned(vec)
+    }
+}
+
+impl<'a, T> TryFrom<Cow<'a, [T]>> for Cow1<'a, T>
+where
+    T: Clone,
+{
+    type Error = Size0Error;
+
+    fn try_from(cow: Cow<'a, [T]>) -> Result<Self, Size0Error> {
+        match cow {
+            Cow::Borrowed(slice) => Slice1::try_from_slice(slice).map(Cow1::Borrowed),
+            Cow::Owned(vec) => Vec1::try_from_vec(vec).map(Cow1::Owned),
+        }
+    }
+}
+
+impl<'a, T> From<Cow1<'a, T>> for Cow<'a, [T]>
+where
+    T: Clone,
+{
+    fn from(cow: Cow1<'a, T>) -> Self {
+        match cow {
+            Cow1::Borrowed(slice) => Cow::Borrowed(slice.as_slice()),
+            Cow1::Owned(vec) => Cow::Owned(vec.into_vec()),
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for Cow1<'a, T>
+where
+    T: Clone + fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice1(), fter)
+    }
+}
+
+impl<'a, 'b, A, B> PartialEq<Cow1<'b, B>> for Cow1<'a, A>
+where
+    A: Clone + PartialEq<B>,
+    B: Clone,
+{
+    fn eq(&self, other: &Cow1<'b, B>) -> bool {
+        self.as_slice1() == other.as_slice1()
+    }
+}
+
+impl<'a, A, B> PartialEq<[B]> for Cow1<'a, A>
+where
+    A: Clone + PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        *self.as_slice1() == *other
+    }
+}
+
+impl<'a, A, B, const N: usize> PartialEq<[B; N]> for Cow1<'a, A>
+where
+    A: Clone + PartialEq<B>,
+{
+    fn eq(&self, other: &[B; N]) -> bool {
+        *self.as_slice1() == *other
+    }
+}
+
+impl<'a, T> Eq for Cow1<'a, T> where T: Clone + Eq {}
+
+impl<'a, T> Hash for Cow1<'a, T>
+where
+    T: Clone + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice1().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn try_from_cow() {
+        let cow = Cow1::try_from(Cow::Borrowed(&[1u8][..])).unwrap();
+        assert!(cow.is_borrowed());
+        let cow = Cow1::try_from(Cow::<[u8]>::Owned(Vec::from([1u8]))).unwrap();
+        assert!(cow.is_owned());
+        assert_eq!(Cow1::try_from(Cow::Borrowed(&[] as &[u8])), Err(Size0Error));
+        assert_eq!(
+            Cow1::try_from(Cow::<[u8]>::Owned(Vec::new())),
+            Err(Size0Error)
+        );
+    }
+
+    #[test]
+    fn to_mut_and_into_owned() {
+        let vec = vec1![1u8, 2];
+        let mut cow = Cow1::from(&vec);
+        assert_eq!(cow.first(), &1);
+        *cow.to_mut().last_mut() = 3;
+        assert!(cow.is_owned());
+        assert_eq!(cow, [1u8, 3]);
+        assert_eq!(cow.clone().into_owned(), vec1![1u8, 3]);
+        assert_eq!(Cow::from(cow), Cow::<[u8]>::Owned(Vec::from([1u8, 3])));
+        assert_eq!(Cow1::from(vec.as_slice1()), Cow1::from(vec1![1u8, 2]));
+    }
+}
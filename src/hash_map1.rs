@@ -0,0 +1,578 @@
+//! A non-empty hash map type (`HashMap1`) and its entry API, requires the `std` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::HashMap1;
+//!
+//! let mut routes = HashMap1::new("/", "index");
+//! routes.insert("/about", "about");
+//! *routes.entry("/").or_insert("home") = "start";
+//! assert_eq!(routes["/"], "start");
+//! assert_eq!(routes.remove("/about"), Ok(Some("about")));
+//! // removing the last route fails
+//! assert!(routes.remove("/").is_err());
+//! ```
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    num::NonZeroUsize,
+    ops::{Deref, Index},
+};
+
+use std::collections::{
+    hash_map::{self, RandomState},
+    HashMap,
+};
+
+use crate::{retain_keep_last, Size0Error, Vec1};
+
+/// `HashMap` wrapper which guarantees to have at least 1 entry.
+///
+/// Dereferences to `&HashMap<K, V, S>`, methods which could make the map
+/// empty return a `Result`.
+#[derive(Clone)]
+pub struct HashMap1<K, V, S = RandomState>(HashMap<K, V, S>);
+
+impl<K, V> HashMap1<K, V>
+where
+    K: Eq + Hash,
+{
+    /// Creates a new `HashMap1` containing just the given entry.
+    pub fn new(key: K, value: V) -> Self {
+        let mut map = HashMap::new();
+        map.insert(key, value);
+        HashMap1(map)
+    }
+}
+
+impl<K, V, S> HashMap1<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Creates a new `HashMap1` containing just the given entry using `hasher` to hash the keys.
+    pub fn with_hasher(key: K, value: V, hasher: S) -> Self {
+        let mut map = HashMap::with_hasher(hasher);
+        map.insert(key, value);
+        HashMap1(map)
+    }
+
+    /// Tries to create a `HashMap1` from a `HashMap`.
+    ///
+    /// # Errors
+    ///
+    /// If the map is empty.
+    pub fn try_from_map(map: HashMap<K, V, S>) -> Result<Self, Size0Error> {
+        if map.is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(HashMap1(map))
+        }
+    }
+
+    /// Returns the underlying `HashMap`.
+    pub fn into_map(self) -> HashMap<K, V, S> {
+        self.0
+    }
+
+    /// Returns a reference to the underlying `HashMap`.
+    pub fn as_map(&self) -> &HashMap<K, V, S> {
+        &self.0
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns an arbitrary entry of the map.
+    pub fn any(&self) -> (&K, &V) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.iter().next().unwrap()
+    }
+
+    /// Inserts a key-value pair into the map, see [`HashMap::insert()`].
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Returns an iterator over all entries with mutable references to the values.
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, K, V> {
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator over mutable references to all values.
+    pub fn values_mut(&mut self) -> hash_map::ValuesMut<'_, K, V> {
+        self.0.values_mut()
+    }
+
+    /// Gets the entry for the given key for in-place manipulation.
+    ///
+    /// Unlike [`HashMap::entry()`] removing the entry fails if it's the last entry.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let is_last = self.0.len() == 1;
+        match self.0.entry(key) {
+            hash_map::Entry::Occupied(entry) => Entry::Occupied(OccupiedEntry { entry, is_last }),
+            hash_map::Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+
+    /// Removes a key from the map returning the value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If the key is the only key in the map an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, Size0Error>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key)
+            .map(|entry| entry.map(|(_, value)| value))
+    }
+
+    /// Removes a key from the map returning the stored key and value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If the key is the only key in the map an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Result<Option<(K, V)>, Size0Error>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.0.len() > 1 {
+            Ok(self.0.remove_entry(key))
+        } else if self.0.contains_key(key) {
+            Err(Size0Error)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Removes all entries except the ones which the predicate says need to be retained.
+    ///
+    /// Like [`Vec1::retain()`] this fails the moment the last entry would be removed,
+    /// not removing that entry. **All other non retained entries will still be removed.**
+    ///
+    /// As entries are visited in arbitrary order, which entry is kept in
+    /// that case is unspecified.
+    ///
+    /// # Errors
+    ///
+    /// If the last entry would be removed instead of removing it a `Size0Error` is
+    /// returned.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), Size0Error>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut kept_any = false;
+        let mut keep = retain_keep_last(self.0.len(), &mut kept_any);
+        self.0.retain(move |key, value| keep(f(key, value)));
+        if kept_any {
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
+    }
+}
+
+/// A view into a single entry of a [`HashMap1`], see [`HashMap1::entry()`].
+pub enum Entry<'a, K, V> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// A vacant entry, as it can only be inserted into the std type is used.
+    Vacant(hash_map::VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant and returns a mutable reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if the entry is vacant and returns a mutable
+    /// reference to the value.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts `V::default()` if the entry is vacant and returns a mutable reference to the value.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(V::default()),
+        }
+    }
+
+    /// Calls `f` with the value if the entry is occupied.
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+/// A view into an occupied entry of a [`HashMap1`].
+pub struct OccupiedEntry<'a, K, V> {
+    entry: hash_map::OccupiedEntry<'a, K, V>,
+    is_last: bool,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        self.entry.key()
+    }
+
+    /// Returns a reference to the value.
+    pub fn get(&self) -> &V {
+        self.entry.get()
+    }
+
+    /// Returns a mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.entry.get_mut()
+    }
+
+    /// Converts the entry into a mutable reference to the value.
+    pub fn into_mut(self) -> &'a mut V {
+        self.entry.into_mut()
+    }
+
+    /// Replaces the value returning the old value.
+    pub fn insert(&mut self, value: V) -> V {
+        self.entry.insert(value)
+    }
+
+    /// Removes the entry returning the value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If it's the last entry an error is returned **without** removing it.
+    pub fn remove(self) -> Result<V, Size0Error> {
+        self.remove_entry().map(|(_, value)| value)
+    }
+
+    /// Removes the entry returning the key and value, if it isn't the last entry.
+    ///
+    /// # Errors
+    ///
+    /// If it's the last entry an error is returned **without** removing it.
+    pub fn remove_entry(self) -> Result<(K, V), Size0Error> {
+        if self.is_last {
+            Err(Size0Error)
+        } else {
+            Ok(self.entry.remove_entry())
+        }
+    }
+}
+
+impl<K, V, S> Deref for HashMap1<K, V, S> {
+    type Target = HashMap<K, V, S>;
+
+    fn deref(&self) -> &HashMap<K, V, S> {
+        &self.0
+    }
+}
+
+impl<K, V, S> AsRef<HashMap<K, V, S>> for HashMap1<K, V, S> {
+    fn as_ref(&self) -> &HashMap<K, V, S> {
+        &self.0
+    }
+}
+
+impl<K, Q, V, S> Index<&Q> for HashMap1<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        &self.0[key]
+    }
+}
+
+impl<K, V, S> TryFrom<HashMap<K, V, S>> for HashMap1<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = Size0Error;
+
+    fn try_from(map: HashMap<K, V, S>) -> Result<Self, Size0Error> {
+        HashMap1::try_from_map(map)
+    }
+}
+
+impl<K, V, S> From<HashMap1<K, V, S>> for HashMap<K, V, S> {
+    fn from(map: HashMap1<K, V, S>) -> Self {
+        map.0
+    }
+}
+
+impl<K, V, S> From<Vec1<(K, V)>> for HashMap1<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    /// If a key appears multiple times the last value is used.
+    fn from(vec: Vec1<(K, V)>) -> Self {
+        HashMap1(vec.into_iter().collect())
+    }
+}
+
+impl<K, V, S> From<HashMap1<K, V, S>> for Vec1<(K, V)> {
+    fn from(map: HashMap1<K, V, S>) -> Self {
+        //UNWRAP_SAFE: len is at least 1
+        Vec1::try_from_vec(map.0.into_iter().collect()).unwrap()
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashMap1<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<K, V, S> IntoIterator for HashMap1<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashMap1<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashMap1<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = hash_map::IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<K, V, S> fmt::Debug for HashMap1<K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<K, V, S> PartialEq for HashMap1<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, V, S> PartialEq<HashMap<K, V, S>> for HashMap1<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &HashMap<K, V, S>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<K, V, S> Eq for HashMap1<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    use core::marker::PhantomData;
+    use serde::{
+        de::{Deserialize, Deserializer, Error as _, MapAccess, Visitor},
+        ser::{Serialize, SerializeMap, Serializer},
+    };
+
+    impl<K, V, S> Serialize for HashMap1<K, V, S>
+    where
+        K: Serialize,
+        V: Serialize,
+    {
+        fn serialize<SE: Serializer>(&self, serializer: SE) -> Result<SE::Ok, SE::Error> {
+            let mut map_ser = serializer.serialize_map(Some(self.len()))?;
+            for (key, value) in self.iter() {
+                map_ser.serialize_entry(key, value)?;
+            }
+            map_ser.end()
+        }
+    }
+
+    impl<'de, K, V, S> Deserialize<'de> for HashMap1<K, V, S>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        S: BuildHasher + Default,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(MapVisitor {
+                _type_carry: PhantomData,
+            })
+        }
+    }
+
+    struct MapVisitor<K, V, S> {
+        _type_carry: PhantomData<HashMap<K, V, S>>,
+    }
+
+    impl<'de, K, V, S> Visitor<'de> for MapVisitor<K, V, S>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        S: BuildHasher + Default,
+    {
+        type Value = HashMap1<K, V, S>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map = HashMap::with_hasher(S::default());
+            while let Some((key, value)) = access.next_entry()? {
+                map.insert(key, value);
+            }
+            HashMap1::try_from_map(map).map_err(A::Error::custom)
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn try_from_map() {
+        assert_eq!(
+            HashMap1::try_from(HashMap::from([(1u8, 'a')])),
+            Ok(HashMap1::new(1u8, 'a'))
+        );
+        assert_eq!(
+            HashMap1::<u8, char>::try_from_map(HashMap::new()),
+            Err(Size0Error)
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let mut map = HashMap1::<_, _>::from(vec1![(1u8, 'a'), (2, 'b')]);
+        assert_eq!(map.remove(&3), Ok(None));
+        assert_eq!(map.remove(&1), Ok(Some('a')));
+        assert_eq!(map.remove_entry(&2), Err(Size0Error));
+        assert_eq!(map.any(), (&2, &'b'));
+    }
+
+    #[test]
+    fn retain() {
+        let mut map = HashMap1::<_, _>::from(vec1![(1u8, 1u8), (2, 2), (3, 3)]);
+        assert_eq!(
+            map.retain(|k, v| {
+                *v += 1;
+                k % 2 == 1
+            }),
+            Ok(())
+        );
+        assert_eq!(map, HashMap::from([(1, 2), (3, 4)]));
+        assert_eq!(map.retain(|_, _| false), Err(Size0Error));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn entry() {
+        let mut map = HashMap1::new(1u8, 10u8);
+        *map.entry(2).or_default() += 2;
+        map.entry(1).and_modify(|v| *v += 1).or_insert(0);
+        assert_eq!(map, HashMap::from([(1, 11), (2, 2)]));
+        match map.entry(1) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), Ok(11)),
+            Entry::Vacant(_) => panic!("entry should be occupied"),
+        }
+        match map.entry(2) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), Err(Size0Error)),
+            Entry::Vacant(_) => panic!("entry should be occupied"),
+        }
+        assert_eq!(map[&2], 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let map: HashMap1<u8, u8> = serde_json::from_str(r#"{"1": 2}"#).unwrap();
+        assert_eq!(map, HashMap1::new(1, 2));
+        assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"1":2}"#);
+        let res: Result<HashMap1<u8, u8>, _> = serde_json::from_str("{}");
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,324 @@
+//! A non-empty hash set type (`HashSet1`), requires the `std` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::{vec1, HashSet1};
+//!
+//! let mut tags = HashSet1::new("urgent");
+//! tags.insert("bug");
+//! assert!(tags.contains("bug"));
+//! assert!(tags.remove("urgent").unwrap());
+//! // removing the last tag fails
+//! assert!(tags.remove("bug").is_err());
+//!
+//! let tags = HashSet1::<_>::from(vec1!["a", "b", "a"]);
+//! assert_eq!(tags.len(), 2);
+//! ```
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    num::NonZeroUsize,
+    ops::Deref,
+};
+
+use std::collections::{hash_map::RandomState, hash_set, HashSet};
+
+use crate::{retain_keep_last, Size0Error, Vec1};
+
+/// `HashSet` wrapper which guarantees to have at least 1 element.
+///
+/// Dereferences to `&HashSet<T, S>`, methods which could make the set
+/// empty return a `Result`.
+#[derive(Clone)]
+pub struct HashSet1<T, S = RandomState>(HashSet<T, S>);
+
+impl<T> HashSet1<T>
+where
+    T: Eq + Hash,
+{
+    /// Creates a new `HashSet1` containing just `first`.
+    pub fn new(first: T) -> Self {
+        let mut set = HashSet::new();
+        set.insert(first);
+        HashSet1(set)
+    }
+}
+
+impl<T, S> HashSet1<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Creates a new `HashSet1` containing just `first` using `hasher` to hash the elements.
+    pub fn with_hasher(first: T, hasher: S) -> Self {
+        let mut set = HashSet::with_hasher(hasher);
+        set.insert(first);
+        HashSet1(set)
+    }
+
+    /// Tries to create a `HashSet1` from a `HashSet`.
+    ///
+    /// # Errors
+    ///
+    /// If the set is empty.
+    pub fn try_from_set(set: HashSet<T, S>) -> Result<Self, Size0Error> {
+        if set.is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(HashSet1(set))
+        }
+    }
+
+    /// Returns the underlying `HashSet`.
+    pub fn into_set(self) -> HashSet<T, S> {
+        self.0
+    }
+
+    /// Returns a reference to the underlying `HashSet`.
+    pub fn as_set(&self) -> &HashSet<T, S> {
+        &self.0
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns an arbitrary element of the set.
+    pub fn any(&self) -> &T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.iter().next().unwrap()
+    }
+
+    /// Adds a value to the set, see [`HashSet::insert()`].
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Adds a value to the set, replacing the existing equal value, see [`HashSet::replace()`].
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        self.0.replace(value)
+    }
+
+    /// Removes a value from the set, if it isn't the last element.
+    ///
+    /// Returns whether the value was present in the set.
+    ///
+    /// # Errors
+    ///
+    /// If the value is the only element in the set an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, Size0Error>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.take(value).map(|taken| taken.is_some())
+    }
+
+    /// Removes and returns the value equal to the given one, if it isn't the last element.
+    ///
+    /// # Errors
+    ///
+    /// If the value is the only element in the set an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn take<Q>(&mut self, value: &Q) -> Result<Option<T>, Size0Error>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.0.len() > 1 {
+            Ok(self.0.take(value))
+        } else if self.0.contains(value) {
+            Err(Size0Error)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Removes all elements except the ones which the predicate says need to be retained.
+    ///
+    /// Like [`Vec1::retain()`] this fails the moment the last element would be removed,
+    /// not removing that element. **All other non retained elements will still be removed.**
+    ///
+    /// As elements are visited in arbitrary order, which element is kept in
+    /// that case is unspecified.
+    ///
+    /// # Errors
+    ///
+    /// If the last element would be removed instead of removing it a `Size0Error` is
+    /// returned.
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), Size0Error>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut kept_any = false;
+        let mut keep = retain_keep_last(self.0.len(), &mut kept_any);
+        self.0.retain(move |value| keep(f(value)));
+        if kept_any {
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
+    }
+}
+
+impl<T, S> Deref for HashSet1<T, S> {
+    type Target = HashSet<T, S>;
+
+    fn deref(&self) -> &HashSet<T, S> {
+        &self.0
+    }
+}
+
+impl<T, S> AsRef<HashSet<T, S>> for HashSet1<T, S> {
+    fn as_ref(&self) -> &HashSet<T, S> {
+        &self.0
+    }
+}
+
+impl<T, S> TryFrom<HashSet<T, S>> for HashSet1<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = Size0Error;
+
+    fn try_from(set: HashSet<T, S>) -> Result<Self, Size0Error> {
+        HashSet1::try_from_set(set)
+    }
+}
+
+impl<T, S> From<HashSet1<T, S>> for HashSet<T, S> {
+    fn from(set: HashSet1<T, S>) -> Self {
+        set.0
+    }
+}
+
+impl<T, S> From<Vec1<T>> for HashSet1<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from(vec: Vec1<T>) -> Self {
+        HashSet1(vec.into_iter().collect())
+    }
+}
+
+impl<T, S> From<HashSet1<T, S>> for Vec1<T> {
+    fn from(set: HashSet1<T, S>) -> Self {
+        //UNWRAP_SAFE: len is at least 1
+        Vec1::try_from_vec(set.0.into_iter().collect()).unwrap()
+    }
+}
+
+impl<T, S> Extend<T> for HashSet1<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T, S> IntoIterator for HashSet1<T, S> {
+    type Item = T;
+    type IntoIter = hash_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a HashSet1<T, S> {
+    type Item = &'a T;
+    type IntoIter = hash_set::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T, S> fmt::Debug for HashSet1<T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<T, S> PartialEq for HashSet1<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, S> PartialEq<HashSet<T, S>> for HashSet1<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &HashSet<T, S>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<T, S> Eq for HashSet1<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn try_from_set() {
+        assert_eq!(
+            HashSet1::try_from(HashSet::from([1u8])),
+            Ok(HashSet1::new(1u8))
+        );
+        assert_eq!(
+            HashSet1::<u8>::try_from_set(HashSet::new()),
+            Err(Size0Error)
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let mut set = HashSet1::<_>::from(vec1![1u8, 2]);
+        assert_eq!(set.remove(&3), Ok(false));
+        assert_eq!(set.remove(&1), Ok(true));
+        assert_eq!(set.remove(&2), Err(Size0Error));
+        assert_eq!(set.take(&3), Ok(None));
+        assert_eq!(set.any(), &2);
+    }
+
+    #[test]
+    fn retain() {
+        let mut set = HashSet1::<_>::from(vec1![1u8, 2, 3, 4]);
+        assert_eq!(set.retain(|v| v % 2 == 0), Ok(()));
+        assert_eq!(set, HashSet::from([2, 4]));
+        assert_eq!(set.retain(|_| false), Err(Size0Error));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn into_vec1() {
+        let set = HashSet1::<_>::from(vec1![3u8, 3]);
+        assert_eq!(Vec1::from(set), vec1![3u8]);
+    }
+}
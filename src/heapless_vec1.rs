@@ -0,0 +1,351 @@
+//! A non-empty fixed capacity vector (`HeaplessVec1`) backed by `heapless::Vec`.
+//!
+//! `HeaplessVec1` itself never allocates, e.g. to avoid allocations in hot
+//! paths or to keep data on the stack.
+//!
+//! This does **not** make this crate usable on targets without a global
+//! allocator: the crate always links `alloc` (as `Vec1` is always available),
+//! so binaries depending on it still need a `#[global_allocator]`. Making
+//! `alloc` optional would be a breaking change for users which disabled the
+//! default features, so it's not planned for 1.x.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::HeaplessVec1;
+//!
+//! let mut readings = HeaplessVec1::<u16, 4>::new(12);
+//! readings.push(14).unwrap();
+//! assert_eq!(readings.last(), &14);
+//! assert_eq!(readings.pop(), Ok(14));
+//! // the last element can't be removed
+//! assert!(readings.pop().is_err());
+//! ```
+
+use core::{
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+};
+
+use heapless::Vec;
+
+use crate::{Size0Error, Slice1};
+
+/// `heapless::Vec` wrapper which guarantees to have at least 1 element.
+///
+/// Like `Vec1` it dereferences to `&[T]`/`&mut [T]`, methods which could
+/// make the vector empty return a `Result` and methods like `first` and
+/// `last` are shadowed by methods which don't return a `Option`.
+///
+/// A `HeaplessVec1<T, 0>` can't be created, trying to do so fails to compile.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HeaplessVec1<T, const N: usize>(Vec<T, N>);
+
+impl<T, const N: usize> HeaplessVec1<T, N> {
+    const CAPACITY_NOT_ZERO: () = assert!(N > 0, "HeaplessVec1 requires a capacity of at least 1");
+
+    /// Creates a new `HeaplessVec1` containing just `first`.
+    pub fn new(first: T) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_NOT_ZERO;
+        let mut vec = Vec::new();
+        if vec.push(first).is_err() {
+            unreachable!("capacity is at least 1");
+        }
+        HeaplessVec1(vec)
+    }
+
+    /// Tries to create a `HeaplessVec1` from a `heapless::Vec`.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is empty.
+    pub fn try_from_vec(vec: Vec<T, N>) -> Result<Self, Size0Error> {
+        if vec.is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(HeaplessVec1(vec))
+        }
+    }
+
+    /// Returns the underlying `heapless::Vec`.
+    pub fn into_inner(self) -> Vec<T, N> {
+        self.0
+    }
+
+    /// Returns the elements as slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the elements as mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// Returns the elements as a non-empty `Slice1`.
+    pub fn as_slice1(&self) -> &Slice1<T> {
+        // SAFETY: only a layout cast, a (leak-)emptied vector can only cause panics
+        unsafe { Slice1::from_slice_unchecked(&self.0) }
+    }
+
+    /// Returns the elements as a mutable non-empty `Slice1`.
+    pub fn as_mut_slice1(&mut self) -> &mut Slice1<T> {
+        // SAFETY: only a layout cast, a (leak-)emptied vector can only cause panics
+        unsafe { Slice1::from_mut_slice_unchecked(&mut self.0) }
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns the (fixed) capacity.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Returns `true` if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.0.is_full()
+    }
+
+    /// Returns a reference to the first element.
+    pub fn first(&self) -> &T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.first().unwrap()
+    }
+
+    /// Returns a mutable reference to the first element.
+    pub fn first_mut(&mut self) -> &mut T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.first_mut().unwrap()
+    }
+
+    /// Returns a reference to the last element.
+    pub fn last(&self) -> &T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.last().unwrap()
+    }
+
+    /// Returns a mutable reference to the last element.
+    pub fn last_mut(&mut self) -> &mut T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.last_mut().unwrap()
+    }
+
+    /// Appends an element to the back.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is full the element is returned as error.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        self.0.push(value)
+    }
+
+    /// Inserts an element at position `index`.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is full the element is returned as error.
+    ///
+    /// # Panics
+    ///
+    /// If `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), T> {
+        self.0.insert(index, value)
+    }
+
+    /// Remove the last element from this vector, if there is more than one element in it.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn pop(&mut self) -> Result<T, Size0Error> {
+        if self.0.len() > 1 {
+            //UNWRAP_SAFE: pop on len > 1 can not be none
+            Ok(self.0.pop().unwrap())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Calls `remove` on the inner vector if length >= 2.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater or equal then `len`.
+    pub fn remove(&mut self, index: usize) -> Result<T, Size0Error> {
+        if self.0.len() > 1 {
+            Ok(self.0.remove(index))
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Calls `swap_remove` on the inner vector if length >= 2.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater or equal then `len`.
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, Size0Error> {
+        if self.0.len() > 1 {
+            Ok(self.0.swap_remove(index))
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Calls `truncate` on the inner vector if `len` is not 0.
+    ///
+    /// # Errors
+    ///
+    /// If `len` is 0 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn truncate(&mut self, len: usize) -> Result<(), Size0Error> {
+        if len > 0 {
+            self.0.truncate(len);
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for HeaplessVec1<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for HeaplessVec1<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for HeaplessVec1<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for HeaplessVec1<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T, N>> for HeaplessVec1<T, N> {
+    type Error = Size0Error;
+
+    fn try_from(vec: Vec<T, N>) -> Result<Self, Size0Error> {
+        HeaplessVec1::try_from_vec(vec)
+    }
+}
+
+impl<T, const N: usize> From<HeaplessVec1<T, N>> for Vec<T, N> {
+    fn from(vec: HeaplessVec1<T, N>) -> Self {
+        vec.0
+    }
+}
+
+impl<T, const N: usize> IntoIterator for HeaplessVec1<T, N> {
+    type Item = T;
+    type IntoIter = <Vec<T, N> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a HeaplessVec1<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut HeaplessVec1<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for HeaplessVec1<T, N>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, fter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<A, B, const N: usize> PartialEq<[B]> for HeaplessVec1<A, N>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<A, B, const N: usize, const M: usize> PartialEq<[B; M]> for HeaplessVec1<A, N>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B; M]) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_vec() {
+        let vec: Vec<u8, 2> = Vec::from_slice(&[1]).unwrap();
+        assert_eq!(HeaplessVec1::try_from(vec), Ok(HeaplessVec1::new(1u8)));
+        assert_eq!(
+            HeaplessVec1::<u8, 2>::try_from_vec(Vec::new()),
+            Err(Size0Error)
+        );
+    }
+
+    #[test]
+    fn push_and_remove() {
+        let mut vec = HeaplessVec1::<u8, 3>::new(1);
+        assert_eq!(vec.push(2), Ok(()));
+        assert_eq!(vec.insert(0, 0), Ok(()));
+        assert!(vec.is_full());
+        assert_eq!(vec.push(3), Err(3));
+        assert_eq!(vec, [0u8, 1, 2]);
+        assert_eq!(vec.swap_remove(0), Ok(0));
+        assert_eq!(vec.remove(0), Ok(2));
+        assert_eq!(vec.remove(0), Err(Size0Error));
+        assert_eq!(vec.pop(), Err(Size0Error));
+        assert_eq!(vec.truncate(0), Err(Size0Error));
+        assert_eq!((vec.first(), vec.last()), (&1, &1));
+        assert_eq!(vec.as_slice1().len_nonzero().get(), 1);
+    }
+}
@@ -0,0 +1,332 @@
+//! A non-empty iterator adapter (`Iterator1`).
+//!
+//! `Iterator1` wraps an iterator which is guaranteed to yield at least one
+//! item. It intentionally does not implement `Iterator` itself (as calling
+//! `next` would void the guarantee), instead adapters which preserve the
+//! guarantee are provided directly and [`Iterator1::into_iter()`] can be
+//! used to fall back to a normal iterator.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::vec1;
+//!
+//! let values = vec1![3, 7, 1];
+//! // no `Option` to handle
+//! assert_eq!(values.iter1().max(), &7);
+//! let doubled = values.into_iter1().map(|v| v * 2).rev().collect_vec1();
+//! assert_eq!(doubled, vec1![2, 14, 6]);
+//! ```
+//!
+//! Generic code can use [`IntoIterator1`] to accept anything which yields
+//! at least one item:
+//!
+//! ```
+//! use vec1::{vec1, BTreeSet1, IntoIterator1};
+//!
+//! fn largest<C>(values: C) -> C::Item
+//! where
+//!     C: IntoIterator1,
+//!     C::Item: Ord,
+//! {
+//!     values.into_iter1().max()
+//! }
+//!
+//! assert_eq!(largest(&vec1![3, 7, 1]), &7);
+//! assert_eq!(largest(BTreeSet1::from(vec1![2, 4])), 4);
+//! ```
+
+use core::iter::{Chain, Map, Rev};
+
+use crate::{BTreeMap1, BTreeSet1, Slice1, Vec1};
+#[cfg(feature = "std")]
+use crate::{HashMap1, HashSet1};
+
+/// Iterator wrapper which guarantees to yield at least 1 item.
+#[derive(Debug, Clone)]
+pub struct Iterator1<I>(I);
+
+impl<I> Iterator1<I>
+where
+    I: Iterator,
+{
+    /// Creates an `Iterator1` without checking that the iterator yields at least one item.
+    ///
+    /// # Safety
+    ///
+    /// The iterator must yield at least one item.
+    ///
+    /// This is a logic invariant, breaking it can only cause panics, e.g. in
+    /// [`Iterator1::first()`]. Unsafe code must not rely on it, as it can't be
+    /// fully upheld (e.g. `Vec1::iter1` doesn't notice if the vector was
+    /// emptied by leaking a `Drain`).
+    pub unsafe fn new_unchecked(iter: I) -> Self {
+        Iterator1(iter)
+    }
+
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+
+    /// Returns the first item.
+    pub fn first(self) -> I::Item {
+        self.split_first().0
+    }
+
+    /// Returns the first item and a iterator over the remaining items.
+    pub fn split_first(mut self) -> (I::Item, I) {
+        //UNWRAP_SAFE: yields at least one item
+        let first = self.0.next().unwrap();
+        (first, self.0)
+    }
+
+    /// Returns the last item.
+    pub fn last(self) -> I::Item {
+        //UNWRAP_SAFE: yields at least one item
+        self.0.last().unwrap()
+    }
+
+    /// Returns the maximum item, see [`Iterator::max()`].
+    pub fn max(self) -> I::Item
+    where
+        I::Item: Ord,
+    {
+        //UNWRAP_SAFE: yields at least one item
+        self.0.max().unwrap()
+    }
+
+    /// Returns the minimum item, see [`Iterator::min()`].
+    pub fn min(self) -> I::Item
+    where
+        I::Item: Ord,
+    {
+        //UNWRAP_SAFE: yields at least one item
+        self.0.min().unwrap()
+    }
+
+    /// Reduces the items to a single one, see [`Iterator::reduce()`].
+    pub fn reduce<F>(self, f: F) -> I::Item
+    where
+        F: FnMut(I::Item, I::Item) -> I::Item,
+    {
+        //UNWRAP_SAFE: yields at least one item
+        self.0.reduce(f).unwrap()
+    }
+
+    /// Maps each item, see [`Iterator::map()`].
+    pub fn map<B, F>(self, f: F) -> Iterator1<Map<I, F>>
+    where
+        F: FnMut(I::Item) -> B,
+    {
+        Iterator1(self.0.map(f))
+    }
+
+    /// Chains another iterator after this one, see [`Iterator::chain()`].
+    pub fn chain<U>(self, other: U) -> Iterator1<Chain<I, U::IntoIter>>
+    where
+        U: IntoIterator<Item = I::Item>,
+    {
+        Iterator1(self.0.chain(other))
+    }
+
+    /// Reverses the iteration order, see [`Iterator::rev()`].
+    pub fn rev(self) -> Iterator1<Rev<I>>
+    where
+        I: DoubleEndedIterator,
+    {
+        Iterator1(self.0.rev())
+    }
+
+    /// Collects all items into a `Vec1`.
+    pub fn collect_vec1(self) -> Vec1<I::Item> {
+        //UNWRAP_SAFE: yields at least one item
+        Vec1::try_from_vec(self.0.collect()).unwrap()
+    }
+}
+
+impl<I> IntoIterator for Iterator1<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+    type IntoIter = I;
+
+    fn into_iter(self) -> I {
+        self.0
+    }
+}
+
+/// Conversion into an [`Iterator1`], implemented for all non-empty containers.
+///
+/// This is the non-empty counterpart of `IntoIterator`.
+pub trait IntoIterator1: IntoIterator {
+    /// Creates a non-empty iterator from a value.
+    fn into_iter1(self) -> Iterator1<Self::IntoIter>;
+}
+
+impl<I> IntoIterator1 for Iterator1<I>
+where
+    I: Iterator,
+{
+    fn into_iter1(self) -> Iterator1<I> {
+        self
+    }
+}
+
+impl<'a, T> IntoIterator1 for &'a Slice1<T> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        self.iter1()
+    }
+}
+
+impl<'a, T> IntoIterator1 for &'a mut Slice1<T> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        self.iter1_mut()
+    }
+}
+
+impl<T> IntoIterator1 for BTreeSet1<T> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+impl<'a, T> IntoIterator1 for &'a BTreeSet1<T> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+impl<K, V> IntoIterator1 for BTreeMap1<K, V> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+impl<'a, K, V> IntoIterator1 for &'a BTreeMap1<K, V> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+impl<'a, K, V> IntoIterator1 for &'a mut BTreeMap1<K, V> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> IntoIterator1 for HashSet1<T, S> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, S> IntoIterator1 for &'a HashSet1<T, S> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> IntoIterator1 for HashMap1<K, V, S> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, V, S> IntoIterator1 for &'a HashMap1<K, V, S> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, V, S> IntoIterator1 for &'a mut HashMap1<K, V, S> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> IntoIterator1 for crate::HeaplessVec1<T, N> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.into_iter()) }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, T, const N: usize> IntoIterator1 for &'a crate::HeaplessVec1<T, N> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        self.as_slice1().iter1()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, T, const N: usize> IntoIterator1 for &'a mut crate::HeaplessVec1<T, N> {
+    fn into_iter1(self) -> Iterator1<Self::IntoIter> {
+        self.as_mut_slice1().iter1_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn consumers() {
+        let vec = vec1![2u8, 5, 1];
+        assert_eq!(vec.iter1().first(), &2);
+        assert_eq!(vec.iter1().last(), &1);
+        assert_eq!(vec.iter1().max(), &5);
+        assert_eq!(vec.iter1().min(), &1);
+        assert_eq!(vec.iter1().map(|v| *v).reduce(|a, b| a * b), 10);
+        let (first, rest) = vec.iter1().split_first();
+        assert_eq!((first, rest.len()), (&2, 2));
+    }
+
+    #[test]
+    fn adapters() {
+        let mut vec = vec1![1u8, 2];
+        for v in vec.iter1_mut() {
+            *v += 1;
+        }
+        assert_eq!(vec, [2, 3]);
+        let chained = vec.iter1().map(|v| v * 2).chain([1]).rev();
+        assert_eq!(chained.collect_vec1(), vec1![1u8, 6, 4]);
+        assert_eq!(vec.into_iter1().into_iter().count(), 2);
+    }
+
+    #[test]
+    fn into_iterator1() {
+        fn sum<C>(values: C) -> u8
+        where
+            C: IntoIterator1<Item = u8>,
+        {
+            values.into_iter1().reduce(|a, b| a + b)
+        }
+
+        assert_eq!(sum(vec1![1u8, 2]), 3);
+        assert_eq!(sum(vec1![1u8, 2].iter1().map(|v| *v)), 3);
+        assert_eq!(sum(BTreeSet1::from(vec1![2u8, 2])), 2);
+        assert_eq!(
+            sum(BTreeMap1::new(1u8, ()).into_iter1().map(|(k, ())| k)),
+            1
+        );
+    }
+}
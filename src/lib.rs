@@ -42,7 +42,131 @@
 //!            `SmallVec1` if both `serde` and `smallvec-v1` features are enabled. Note that
 //!            enabling both `serde` and `smallvec-v1` implements `Serialize` and `Deserialize`
 //!            for `SmallVec1` but will *not* enable `smallvec/serde` and as such will not
-//!            implement the `serde` traits for `smallvec::SmallVec`.
+//!            implement the `serde` traits for `smallvec::SmallVec`. The `vec1::serde` module
+//!            provides helpers for alternative representations, e.g. `empty_as_none` for
+//!            `Option<Vec1<T>>` fields.
+//!
+//! - `serde_with`: Implements `serde_with::{SerializeAs, DeserializeAs}` for `Vec1`, so adapters
+//!                 like `#[serde_as(as = "Vec1<DisplayFromStr>")]` can be used on `Vec1` fields.
+//!
+//! - `fake`: Implements `fake::Dummy` for `Vec1` (and `SmallVec1` if `smallvec-v1` is enabled).
+//!           Generated vectors always have at least one element, if a length config is
+//!           given the generated length is clamped to be at least 1.
+//!
+//! - `async-graphql`: Implements `async_graphql::{InputType, OutputType}` for `Vec1`.
+//!                    Parsing an empty list as `Vec1` fails with an input value error.
+//!
+//! - `ts-rs`: Implements `ts_rs::TS` for `Vec1`, it's exported as `[T, ...Array<T>]`.
+//!
+//! - `postgres`: Implements `postgres_types::{ToSql, FromSql}` (as used by `tokio-postgres`)
+//!               for `Vec1` mapping it to postgres arrays, reading a empty array fails.
+//!
+//! - `diesel`: Implements diesel's `ToSql`/`FromSql`/`AsExpression` for `Vec1` mapping it to
+//!             postgres arrays (`Array<ST>`), reading a empty array fails.
+//!
+//! - `redis`: Implements `redis::{ToRedisArgs, FromRedisValue}` for `Vec1`, failing with a
+//!            `TypeError` on empty (or nil) replies.
+//!
+//! - `sqlx-postgres`: Implements `sqlx::{Type, Encode, Decode}` for `Vec1` mapping it to postgres
+//!                    arrays, decoding an empty array fails. Requires `std`.
+//!
+//! - `uniffi`: Implements the uniffi `Lift`/`Lower` (and related) traits for `Vec1`, so it can be
+//!             used in uniffi exported functions. Foreign bindings see it as a sequence, lifting an
+//!             empty sequence fails.
+//!
+//! - `wasm`: Implements `From<Vec1<T>>` for `js_sys::Array` and `TryFrom<js_sys::Array>` for
+//!           `Vec1<T>` (with `T: JsCast`), checking that the array is not empty and that all
+//!           elements have the right type. Conversions of the elements themselves can only be
+//!           tested on a wasm target.
+//!
+//! - `im`: Implements `From<Vec1<T>>` for `im::Vector<T>` and `TryFrom<im::Vector<T>>` for `Vec1<T>`.
+//!
+//! - `indexmap`: Implements `TryFrom<IndexSet<T>>` for `Vec1<T>` and `TryFrom<IndexMap<K, V>>`
+//!               for `Vec1<(K, V)>` (keeping the insertion order) as well as the reverse `From`
+//!               conversions. Without the `std` feature the hasher has to be specified explicitly.
+//!
+//! - `bytes`: Implements `From<Vec1<u8>>` for `Bytes`/`BytesMut` and `TryFrom` in the other
+//!            direction, all of them reuse the allocation where possible.
+//!
+//! - `ndarray`: Implements `From<Vec1<T>>` for `ndarray::Array1<T>` and `TryFrom` in the other
+//!              direction.
+//!
+//! - `triomphe`: Implements `From<Vec1<T>>` for `triomphe::Arc<[T]>` and `triomphe::ThinArc<(), T>`,
+//!               works without `std`.
+//!
+//! - `ecow`: Implements `From<Vec1<T>>` for `ecow::EcoVec<T>` and `TryFrom` in the other
+//!           direction.
+//!
+//! - `heapless`: Adds `HeaplessVec1<T, N>`, a non-empty vector backed by `heapless::Vec` which
+//!               never allocates. This crate itself still links `alloc`, so a global allocator is
+//!               required even if only `HeaplessVec1` is used.
+//!
+//! - `rand`: Adds `choose1`, `choose1_mut`, `choose_weighted`, `sample`, `shuffle` and
+//!           `into_shuffled` to `Vec1` and `SmallVec1`.
+//!
+//! - `rkyv`: Implements rkyv's `Archive`/`Serialize`/`Deserialize` for `Vec1`, it's archived as
+//!           `ArchivedVec1` which implements `CheckBytes` rejecting empty archives.
+//!
+//! - `tokio`: Implements `tokio::io::AsyncWrite` for `Vec1<u8>`, like the `std::io::Write` impl.
+//!
+//! - `miniserde`: Implements `miniserde::{Serialize, Deserialize}` for `Vec1`, deserializing
+//!                an empty sequence fails.
+//!
+//! - `rayon`: Implements `rayon::iter::IntoParallelIterator` for `Vec1` and `SmallVec1` (and
+//!            references to them) and adds `par_mapped`, which maps the elements in parallel
+//!            returning a `Vec1`.
+//!
+//! - `speedy`: Implements `speedy::{Readable, Writable}` for `Vec1` and `SmallVec1`, reading an
+//!             empty sequence fails.
+//!
+//! - `juniper`: Implements the juniper GraphQL traits (`GraphQLType`, `FromInputValue`, ...) for
+//!              `Vec1` exposing it as a list, converting a empty list input fails.
+//!
+//! - `rocket`: Implements `rocket::form::FromForm` for `Vec1`, so a missing form/query field
+//!             is reported as a field error.
+//!
+//! - `musli`: Implements `musli::{Encode, Decode}` for `Vec1` (and `SmallVec1`), decoding
+//!            fails for empty sequences.
+//!
+//! - `validator`: Implements `validator::ValidateLength` for `Vec1` (and `SmallVec1`), so
+//!                `#[validate(length(...))]` can be used on fields of this types.
+//!
+//! - `garde`: Implements `garde::rules::length::simple::HasSimpleLength` for `Vec1` (and `SmallVec1`),
+//!            so `#[garde(length(...))]` can be used on fields of this types.
+//!
+//! - `ufmt`: Implements `ufmt::uDebug` for `Vec1` (and `SmallVec1`), formatting it like a slice.
+//!
+//! - `defmt`: Implements `defmt::Format` for `Vec1` (and `SmallVec1`), formatting it like a slice.
+//!
+//! - `zeroize`: Implements `Zeroize` and `ZeroizeOnDrop` for `Vec1` (and `SmallVec1`), as a `Vec1`
+//!              can't be empty zeroizing truncates it to its first (zeroized) element.
+//!
+//! - `testing`: Exposes the [`testing`] module with `assert_invariants` and a operation
+//!              model which downstream crates can use in their fuzz and property tests.
+//!
+//! - `arbitrary`: Implements `arbitrary::Arbitrary` for `Vec1` (and `SmallVec1`), the first element
+//!                is always drawn so the produced vectors are never empty.
+//!
+//! - `proptest`: Adds the `vec1::proptest` module with `vec1`/`smallvec1` strategies
+//!               and implements `proptest::arbitrary::Arbitrary` for `Vec1` (and `SmallVec1`).
+//!
+//! - `disable-default-impl`: Removes the `Default` implementation of `Vec1` (and `SmallVec1`),
+//!                           which creates a vector containing a single `T::default()` element.
+//!                           This can be used to make sure `#[derive(Default)]` doesn't silently
+//!                           produce such a vector. **This feature is not additive, it should only
+//!                           be enabled by the final binary (never by a library).**
+//!
+//! - `unstable-v2-api`: Preview of the API intended for a (hypothetical) v2 release, so that
+//!                     code can be ported incrementally. Currently this:
+//!
+//!     - adds `Vec1::try_splice` and deprecates `Vec1::splice` (which will be renamed as it's fallible)
+//!
+//!     The preview is limited to additive changes (new names and deprecations), as removing or
+//!     changing existing methods would break other crates in the dependency graph. So the already
+//!     deprecated methods (`Vec1::from_vec`, the `try_` prefixed aliases like `try_pop`,
+//!     `try_truncate` and `try_split_off`) are still available with this feature enabled, and
+//!     `try_split_off` keeps its current error behavior. Use their non-deprecated replacements
+//!     (e.g. `pop`, `truncate` and `split_off`) to be ready for v2.
 //!
 //! - `smallvec-v1` : Adds support for a vec1 variation backed by the smallvec crate
 //!                   version 1.x.y. (In the future there will likely be a additional `smallvec-v2`.).
@@ -50,12 +174,33 @@
 //!
 //! - `smallvec-v1-write`: Enables `smallvec/write`, this requires std. As we can't tell cargo to
 //!                        automatically enable `smallvec/write` if and only if `smallvec-v1` and
-//!                        `std` are both enabled this needs to be an extra feature.
+//!                        `std` are both enabled this needs to be an extra feature. Also implements
+//!                        `std::io::Write` for `SmallVec1<A>` where `A: Array<Item = u8>`.
 //!
 //! - `unstable-nightly-try-from-impl` (deprecated) : Was used to enable `TryFrom`/`TryInto` implementations
 //!                                                   before the traits became stable. Doesn't do anything by
 //!                                                   now, but still exist for compatibility reasons.
 //!
+//! # Panics
+//!
+//! Infallible accessors like `first`, `last`, `len_nonzero` or `split_off_first`
+//! rely on the `len >= 1` invariant, the `unwrap`s backing them are marked with
+//! `UNWRAP_SAFE` comments in the source. The invariant is upheld on a best-effort
+//! basis: no method leaves a collection empty on its own, but leaking the guard
+//! returned by `drain` or `splice` (e.g. with `mem::forget`) does, after which the
+//! accessors above panic. It's a logic invariant, so unsafe code must not rely on it.
+//! Most methods which remove elements check the invariant *before* modifying the
+//! collection, but `retain`/`retain_mut` (including the `retain` of `BTreeSet1`,
+//! `BTreeMap1`, `HashSet1` and `HashMap1`) and `edit`/`edit_or_else` apply their
+//! modifications first and then return an error (`retain` keeps one of the elements,
+//! `edit` pushes a sentinel element).
+//!
+//! There is no `no-panic` based feature as `no-panic` can only prove the absence
+//! of panics the optimizer can see through, which isn't the case for a invariant
+//! upheld across method calls. To check the invariant for your own usage use the
+//! `testing` feature, which provides `testing::assert_invariants` and a model
+//! based checker which can be driven by property tests or fuzzers.
+//!
 //! # Rustdoc
 //!
 //! To have all intra-(and inter-) doc links working properly it is
@@ -89,14 +234,23 @@
 //! ------------------------|-----------------------|----------------
 //! patch update            | yes                   | patch (or minor)
 //! minor update            | yes                   | minor
-//! major update            | yes                   | won't happen, smallvec gets a second feature for v2
+//! major update            | yes                   | won't happen, a additional versioned feature is added
 //! patch update            | no                    | patch (or minor)
 //! minor update            | no                    | minor
 //! major update            | no                    | minor
 //!
+//! A "major update" includes `0.x` minor updates (e.g. `rand` `0.9` -> `0.10`), as they
+//! are breaking changes, too.
+//!
 //! If `smallvec` gets a major update a additional feature will be added supporting
 //! both major versions of it *without* introducing a major update for this crate.
 //!
+//! The same applies to all other integration features (`rand`, `redis`, `rkyv`, `heapless`, ...):
+//! A feature is bound to the major version of the dependency it was added with, e.g. the `rand`
+//! feature will always use `rand` `0.9`. Support for a new major version is added as a
+//! additional feature named after the version (like `smallvec-v1`, e.g. `rand-v0_10`), so
+//! both can be enabled at the same time.
+//!
 //! I do my best so that I will never have to release a major version update for this crate as
 //! this would lead to API incompatibilities for other crates using this crate in their public API.
 #![no_std]
@@ -114,13 +268,75 @@ pub extern crate smallvec_v1_;
 #[macro_use]
 mod shared;
 
+mod slice1;
+pub use crate::slice1::Slice1;
+
+mod cow1;
+pub use crate::cow1::Cow1;
+
+mod builder;
+pub use crate::builder::Vec1Builder;
+
+mod vec_n;
+pub use crate::vec_n::{MinLenError, VecN};
+
+mod non_empty;
+pub use crate::non_empty::NonEmptyCollection;
+
+mod iter1;
+pub use crate::iter1::{IntoIterator1, Iterator1};
+
+mod str1;
+pub use crate::str1::Str1;
+
+mod string1;
+pub use crate::string1::String1;
+
+mod btree_set1;
+pub use crate::btree_set1::BTreeSet1;
+
+pub mod btree_map1;
+pub use crate::btree_map1::BTreeMap1;
+
+#[cfg(feature = "heapless")]
+mod heapless_vec1;
+#[cfg(feature = "heapless")]
+pub use crate::heapless_vec1::HeaplessVec1;
+
+#[cfg(feature = "std")]
+mod hash_set1;
+#[cfg(feature = "std")]
+pub use crate::hash_set1::HashSet1;
+
+#[cfg(feature = "std")]
+pub mod hash_map1;
+#[cfg(feature = "std")]
+pub use crate::hash_map1::HashMap1;
+
+#[cfg(feature = "rkyv")]
+mod archived_vec1;
+#[cfg(feature = "rkyv")]
+pub use crate::archived_vec1::ArchivedVec1;
+
+pub mod validation;
+
 #[cfg(feature = "smallvec-v1")]
 pub mod smallvec_v1;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
 use core::{
     fmt,
     iter::{DoubleEndedIterator, ExactSizeIterator, Extend, IntoIterator, Peekable},
     mem::MaybeUninit,
+    num::NonZeroUsize,
     ops::RangeBounds,
     result::Result as StdResult,
 };
@@ -160,16 +376,87 @@ impl fmt::Display for Size0Error {
 #[cfg(any(feature = "std", test))]
 impl Error for Size0Error {}
 
+/// Returns a `retain` predicate for a collection with `len` elements, which takes the
+/// result of the user provided predicate and additionally keeps the last visited
+/// element if no other element was kept.
+///
+/// `kept_any` is set if the user provided predicate kept any element.
+pub(crate) fn retain_keep_last(len: usize, kept_any: &mut bool) -> impl FnMut(bool) -> bool + '_ {
+    let mut visited = 0;
+    move |keep| {
+        visited += 1;
+        *kept_any |= keep;
+        keep || (visited == len && !*kept_any)
+    }
+}
+
+/// Like [`FromIterator`] but for types which can not be created from an empty iterator.
+///
+/// Implemented for `Vec1` (and `SmallVec1`), see [`IteratorExt::collect1()`].
+pub trait TryFromIterator<T>: Sized {
+    /// Tries to create an instance from given iterator.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the iterator doesn't yield any element.
+    fn try_from_iter<I>(iter: I) -> Result<Self, Size0Error>
+    where
+        I: IntoIterator<Item = T>;
+}
+
+/// Extension trait for iterators adding [`IteratorExt::collect1()`].
+pub trait IteratorExt: Iterator + Sized {
+    /// Like [`Iterator::collect()`] but for types implementing [`TryFromIterator`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the iterator doesn't yield any element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec1::{IteratorExt, Vec1, vec1};
+    ///
+    /// let vec: Vec1<u8> = (1..4).collect1().unwrap();
+    /// assert_eq!(vec, vec1![1, 2, 3]);
+    ///
+    /// let err = (1..1).collect1::<Vec1<u8>>();
+    /// assert!(err.is_err());
+    /// ```
+    fn collect1<C>(self) -> Result<C, Size0Error>
+    where
+        C: TryFromIterator<Self::Item>,
+    {
+        C::try_from_iter(self)
+    }
+}
+
+impl<I> IteratorExt for I where I: Iterator {}
+
 /// A macro similar to `vec!` to create a `Vec1`.
 ///
 /// If it is called with less then 1 element a
 /// compiler error is triggered (using `compile_error`
 /// to make sure you know what went wrong).
+///
+/// Like `vec!` it also supports the `vec1![elem; n]` form,
+/// which fails to compile if `n` is a literal `0` and
+/// panics if `n` is `0` at runtime.
+///
+/// ```compile_fail
+/// let vec = vec1::vec1![1u8; 0];
+/// ```
 #[macro_export]
 macro_rules! vec1 {
     () => (
         compile_error!("Vec1 needs at least 1 element")
     );
+    ($elem:expr; 0) => (
+        compile_error!("Vec1 needs at least 1 element")
+    );
+    ($elem:expr; $n:expr) => (
+        $crate::Vec1::try_from_elem($elem, $n).expect("vec1![elem; n] requires n >= 1")
+    );
     ($first:expr $(, $item:expr)* , ) => (
         $crate::vec1!($first $(, $item)*)
     );
@@ -249,6 +536,31 @@ impl<T> Vec1<T> {
         self.0
     }
 
+    /// Creates a `Vec1` directly from a pointer, a length and a capacity.
+    ///
+    /// Like `Vec::from_raw_parts` but the length is a `NonZeroUsize`.
+    ///
+    /// # Safety
+    ///
+    /// All the safety requirements of `Vec::from_raw_parts` apply, which
+    /// means that in practice the parts should come from [`Vec1::into_raw_parts()`]
+    /// (or `Vec::into_raw_parts`/a deconstructed `Vec` with the same element type).
+    pub unsafe fn from_raw_parts(ptr: *mut T, length: NonZeroUsize, capacity: usize) -> Self {
+        // SAFETY: guaranteed by the caller
+        Vec1(unsafe { Vec::from_raw_parts(ptr, length.get(), capacity) })
+    }
+
+    /// Decomposes a `Vec1` into its raw components: pointer, length and capacity.
+    ///
+    /// Like the (unstable) `Vec::into_raw_parts`, the caller is responsible for the
+    /// memory, the only way to free it is to convert it back with [`Vec1::from_raw_parts()`]
+    /// (or `Vec::from_raw_parts`).
+    pub fn into_raw_parts(self) -> (*mut T, NonZeroUsize, usize) {
+        let length = self.len_nonzero();
+        let mut vec = core::mem::ManuallyDrop::new(self.0);
+        (vec.as_mut_ptr(), length, vec.capacity())
+    }
+
     /// Return a reference to the underlying `Vec`.
     pub fn as_vec(&self) -> &Vec<T> {
         &self.0
@@ -414,6 +726,51 @@ impl<T> Vec1<T> {
         }
     }
 
+    /// Inserts all items of `iterable` at `index`, shifting the following elements only once.
+    ///
+    /// Mirrors `SmallVec1::insert_many()`, as inserting can never violate the length >= 1 constraint.
+    ///
+    /// # Panics
+    ///
+    /// If `index > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut v = vec1![1, 5];
+    /// v.insert_many(1, 2..5);
+    /// assert_eq!(v, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_many<I>(&mut self, index: usize, iterable: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.0.splice(index..index, iterable);
+    }
+
+    /// Splits off the tail starting at `at`, keeping the head (which is never empty).
+    ///
+    /// As `at` is at least 1 this can't fail, but the returned tail can be empty.
+    ///
+    /// # Panics
+    ///
+    /// **If `at` is greater then `len`. (In the same way [`Vec.split_off()`] does.)**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1, 2, 3];
+    /// let tail = vec.split_off_rest(NonZeroUsize::new(1).unwrap());
+    /// assert_eq!(vec, vec1![1]);
+    /// assert_eq!(tail, vec![2, 3]);
+    /// ```
+    pub fn split_off_rest(&mut self, at: NonZeroUsize) -> Vec<T> {
+        self.0.split_off(at.get())
+    }
+
     /// Calls `split_off` on the inner vec if both resulting parts have length >= 1.
     ///
     /// **In difference to `split_off` this also returns a `Size0Error` if `at` is
@@ -454,6 +811,10 @@ impl<T> Vec1<T> {
     /// - if the starting point is greater than the end point
     /// - if the end point is greater than the length of the vector.
     ///
+    #[cfg_attr(
+        feature = "unstable-v2-api",
+        deprecated(since = "1.13.0", note = "renamed to `try_splice` in the v2 API")
+    )]
     pub fn splice<R, I>(
         &mut self,
         range: R,
@@ -478,6 +839,56 @@ impl<T> Vec1<T> {
             Ok(Splice { vec_splice })
         }
     }
+
+    /// Preview of the v2 name of [`Vec1::splice()`], see the `unstable-v2-api` feature.
+    ///
+    /// # Errors
+    ///
+    /// See [`Vec1::splice()`].
+    ///
+    /// # Panics
+    ///
+    /// See [`Vec1::splice()`].
+    #[cfg(feature = "unstable-v2-api")]
+    pub fn try_splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<<I as IntoIterator>::IntoIter>, Size0Error>
+    where
+        I: IntoIterator<Item = T>,
+        R: RangeBounds<usize>,
+    {
+        #[allow(deprecated)]
+        self.splice(range, replace_with)
+    }
+
+    /// Like `splice` but infallible as the replacement is not empty.
+    ///
+    /// As the replacement always contains at least 1 element the range can
+    /// cover the whole vector.
+    ///
+    /// # Panics
+    ///
+    /// Under the same conditions as [`Vec::splice()`], i.e. if the starting point is
+    /// greater than the end point or the end point is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut v = vec1![1, 2, 3];
+    /// let removed: Vec<_> = v.splice1(.., vec1![4, 5]).collect();
+    /// assert_eq!(removed, [1, 2, 3]);
+    /// assert_eq!(v, [4, 5]);
+    /// ```
+    pub fn splice1<R>(&mut self, range: R, replace_with: Vec1<T>) -> Splice<alloc::vec::IntoIter<T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let vec_splice = self.0.splice(range, replace_with.0.into_iter().peekable());
+        Splice { vec_splice }
+    }
 }
 
 impl_wrapper! {
@@ -494,6 +905,42 @@ impl<T> Vec1<T>
 where
     T: Clone,
 {
+    /// Creates a `Vec1` containing `len` clones of `element`, like `vec![element; len]`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `len` is 0.
+    pub fn try_from_elem(element: T, len: usize) -> Result<Self, Size0Error> {
+        if len == 0 {
+            Err(Size0Error)
+        } else {
+            Ok(Vec1(alloc::vec![element; len]))
+        }
+    }
+
+    /// Returns all pairs of elements of `self` and `other`.
+    ///
+    /// The pairs are ordered by the element of `self` first and the
+    /// element of `other` second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let product = vec1![1, 2].cartesian_product(&vec1!['a', 'b']);
+    /// assert_eq!(product, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    /// ```
+    pub fn cartesian_product<U>(&self, other: &Vec1<U>) -> Vec1<(T, U)>
+    where
+        U: Clone,
+    {
+        let mut out = Vec::with_capacity(self.len() * other.len());
+        for left in self.iter() {
+            out.extend(other.iter().map(|right| (left.clone(), right.clone())));
+        }
+        Vec1(out)
+    }
+
     pub fn extend_from_within<R>(&mut self, src: R)
     where
         R: RangeBounds<usize>,
@@ -502,6 +949,29 @@ where
     }
 }
 
+impl<T> Vec1<Vec1<T>>
+where
+    T: Clone,
+{
+    /// Like `[V]::join` with a single element separator, but returns a `Vec1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let segments = vec1![vec1!["usr"], vec1!["local", "bin"]];
+    /// assert_eq!(segments.join1(&"/"), vec1!["usr", "/", "local", "bin"]);
+    /// ```
+    pub fn join1(&self, sep: &T) -> Vec1<T> {
+        Vec1(self.0.join(sep))
+    }
+
+    /// Like `[V]::concat` but returns a `Vec1`.
+    pub fn concat1(&self) -> Vec1<T> {
+        Vec1(self.0.concat())
+    }
+}
+
 impl Vec1<u8> {
     /// Works like `&[u8].to_ascii_uppercase()` but returns a `Vec1<T>` instead of a `Vec<T>`
     pub fn to_ascii_uppercase(&self) -> Vec1<u8> {
@@ -703,6 +1173,28 @@ impl TryFrom<CString> for Vec1<u8> {
     }
 }
 
+/// Appends the written bytes, like the `io::Write` impl of `Vec<u8>`.
+///
+/// There is no `io::Read` impl as reading would need to remove bytes from the
+/// front, which could empty the vector. Instead wrap it into a `io::Cursor`,
+/// which implements `io::Read`, `io::BufRead` and `io::Seek` as `Vec1<u8>`
+/// implements `AsRef<[u8]>`:
+///
+/// ```
+/// use std::io::{BufRead, Cursor, Read};
+/// use vec1::Vec1;
+///
+/// let bytes: Vec1<u8> = Vec1::try_from("hello\nworld").unwrap();
+/// let mut cursor = Cursor::new(bytes);
+/// let mut line = String::new();
+/// cursor.read_line(&mut line).unwrap();
+/// assert_eq!(line, "hello\n");
+/// let mut rest = String::new();
+/// cursor.read_to_string(&mut rest).unwrap();
+/// assert_eq!(rest, "world");
+/// // the bytes are still all there
+/// assert_eq!(cursor.into_inner().len(), 11);
+/// ```
 #[cfg(feature = "std")]
 impl io::Write for Vec1<u8> {
     #[inline]
@@ -726,6 +1218,48 @@ impl io::Write for Vec1<u8> {
     }
 }
 
+#[cfg(feature = "tokio")]
+impl tokio::io::AsyncWrite for Vec1<u8> {
+    #[inline]
+    fn poll_write(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> core::task::Poll<io::Result<usize>> {
+        core::pin::Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    #[inline]
+    fn poll_write_vectored(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> core::task::Poll<io::Result<usize>> {
+        core::pin::Pin::new(&mut self.0).poll_write_vectored(cx, bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        tokio::io::AsyncWrite::is_write_vectored(&self.0)
+    }
+
+    #[inline]
+    fn poll_flush(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<io::Result<()>> {
+        core::pin::Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_shutdown(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<io::Result<()>> {
+        core::pin::Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
 impl<T, const N: usize> TryFrom<Vec1<T>> for [T; N] {
     type Error = Vec1<T>;
 
@@ -776,1165 +1310,3196 @@ where
     }
 }
 
-#[cfg(test)]
-mod test {
-    #![allow(non_snake_case)]
+#[cfg(feature = "async-graphql")]
+const _: () = {
+    use async_graphql::{
+        parser::types::Field, registry, resolver_utils::resolve_list, ContextSelectionSet,
+        InputType, InputValueError, InputValueResult, OutputType, Positioned, ServerResult, Value,
+    };
 
-    mod Size0Error {
-        #![allow(non_snake_case)]
-        use super::super::*;
-        use std::error::Error as StdError;
+    /// Works like the implementation for `Vec<T>` but fails to parse empty lists.
+    ///
+    /// GraphQL has no notion of non-empty lists, so in the schema a `Vec1<T>` is a
+    /// normal list (`[T]!`) and the length check only happens at runtime.
+    impl<T> InputType for Vec1<T>
+    where
+        T: InputType,
+    {
+        type RawValueType = Self;
 
-        #[test]
-        fn implements_std_error() {
-            fn comp_check<T: StdError>() {}
-            comp_check::<Size0Error>();
+        fn type_name() -> alloc::borrow::Cow<'static, str> {
+            <Vec<T> as InputType>::type_name()
         }
-    }
-
-    mod Vec1 {
-        use core::num::NonZeroUsize;
-        use proptest::prelude::*;
-        use std::panic::catch_unwind;
 
-        use super::super::*;
-
-        // prevent a type from causing us to use the wrong type
-        #[allow(unused_macros)]
-        macro_rules! vec {
-            ($($any:tt)*) => {
-                compile_error!("typo? vec! => vec1!")
-            };
+        fn qualified_type_name() -> String {
+            <Vec<T> as InputType>::qualified_type_name()
         }
 
-        #[test]
-        fn new_vec1_macro() {
-            let a = vec1![1u8, 10u8, 3u8];
-            assert_eq!(a, &[1, 10, 3]);
+        fn create_type_info(registry: &mut registry::Registry) -> String {
+            <Vec<T> as InputType>::create_type_info(registry)
+        }
 
-            let a = vec1![40u8];
-            assert_eq!(a, &[40]);
+        fn parse(value: Option<Value>) -> InputValueResult<Self> {
+            let vec = <Vec<T> as InputType>::parse(value).map_err(InputValueError::propagate)?;
+            Vec1::try_from_vec(vec).map_err(InputValueError::custom)
+        }
 
-            //TODO comptest vec1![] => compiler error
+        fn to_value(&self) -> Value {
+            <Vec<T> as InputType>::to_value(&self.0)
         }
 
-        #[test]
-        fn new() {
-            let a = Vec1::new(1u8);
-            assert_eq!(a.len(), 1);
-            assert_eq!(a.first(), &1u8);
+        fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+            Some(self)
         }
+    }
 
-        #[test]
-        fn with_capacity() {
-            let a = Vec1::with_capacity(2u8, 10);
-            assert_eq!(a.len(), 1);
-            assert_eq!(a.first(), &2u8);
-            assert_eq!(a.capacity(), 10);
+    #[async_graphql::async_trait::async_trait]
+    impl<T> OutputType for Vec1<T>
+    where
+        T: OutputType,
+    {
+        fn type_name() -> alloc::borrow::Cow<'static, str> {
+            <Vec<T> as OutputType>::type_name()
         }
 
-        #[test]
-        fn capacity() {
-            let a = Vec1::with_capacity(2u8, 123);
-            assert_eq!(a.capacity(), 123);
+        fn qualified_type_name() -> String {
+            <Vec<T> as OutputType>::qualified_type_name()
         }
 
-        #[test]
-        fn reserve() {
-            let mut a = Vec1::with_capacity(1u8, 1);
-            assert_eq!(a.capacity(), 1);
-            a.reserve(15);
-            assert!(a.capacity() > 10);
+        fn create_type_info(registry: &mut registry::Registry) -> String {
+            <Vec<T> as OutputType>::create_type_info(registry)
         }
 
-        #[test]
-        fn reserve_exact() {
-            let mut a = Vec1::with_capacity(1u8, 1);
-            assert_eq!(a.capacity(), 1);
-            a.reserve_exact(11);
-            assert_eq!(a.capacity(), 12);
+        async fn resolve(
+            &self,
+            ctx: &ContextSelectionSet<'_>,
+            field: &Positioned<Field>,
+        ) -> ServerResult<Value> {
+            resolve_list(ctx, field, self, Some(self.len())).await
         }
+    }
+};
 
-        #[test]
-        fn shrink_to_fit() {
-            let mut a = Vec1::with_capacity(1u8, 20);
-            a.push(13u8);
-            a.shrink_to_fit();
-            assert_eq!(a.capacity(), 2);
-        }
+#[cfg(feature = "ts-rs")]
+const _: () = {
+    use alloc::format;
+    use ts_rs::{Dummy, TypeVisitor, TS};
 
-        #[test]
-        fn into_boxed_slice() {
-            let a = vec1![32u8, 12u8];
-            let boxed: Box<[u8]> = a.into_boxed_slice();
-            assert_eq!(&*boxed, &[32u8, 12u8]);
-        }
+    /// Exports `Vec1<T>` as the non-empty tuple type `[T, ...Array<T>]`.
+    impl<T> TS for Vec1<T>
+    where
+        T: TS,
+    {
+        type WithoutGenerics = Vec1<Dummy>;
 
-        #[test]
-        fn truncate() {
-            let mut a = vec1![42u8, 32, 1];
-            a.truncate(1).unwrap();
-            assert_eq!(a.len(), 1);
-            assert_eq!(a, &[42u8]);
+        fn ident() -> String {
+            String::from("Array")
+        }
 
-            a.truncate(0).unwrap_err();
+        fn name() -> String {
+            format!("[{0}, ...Array<{0}>]", T::name())
         }
 
-        #[test]
-        fn try_truncate() {
-            #![allow(deprecated)]
+        fn inline() -> String {
+            format!("[{0}, ...Array<{0}>]", T::inline())
+        }
 
-            let mut a = vec1![42u8, 32, 1];
-            a.try_truncate(1).unwrap();
-            assert_eq!(a.len(), 1);
-            assert_eq!(a, &[42u8]);
+        fn visit_dependencies(v: &mut impl TypeVisitor)
+        where
+            Self: 'static,
+        {
+            T::visit_dependencies(v);
+        }
 
-            a.try_truncate(0).unwrap_err();
+        fn visit_generics(v: &mut impl TypeVisitor)
+        where
+            Self: 'static,
+        {
+            T::visit_generics(v);
+            v.visit::<T>();
         }
 
-        #[test]
-        fn as_slice() {
-            let a = vec1![22u8, 12, 9];
-            let b: &[u8] = a.as_slice();
-            assert_eq!(b, &[22u8, 12, 9]);
+        fn decl() -> String {
+            panic!("{} cannot be declared", Self::name())
         }
 
-        #[test]
-        fn as_mut_slice() {
-            let mut a = vec1![22u8, 12, 9];
-            let b: &mut [u8] = a.as_mut_slice();
-            assert_eq!(b, &mut [22u8, 12, 9]);
+        fn decl_concrete() -> String {
+            panic!("{} cannot be declared", Self::name())
         }
 
-        #[test]
-        fn as_ptr() {
-            let a = vec1![22u8, 12, 9];
-            let a_ptr = a.as_ptr();
-            let a = a.into_vec();
-            let a_ptr2 = a.as_ptr();
-            assert_eq!(a_ptr, a_ptr2);
+        fn inline_flattened() -> String {
+            panic!("{} cannot be flattened", Self::name())
         }
+    }
+};
 
-        #[test]
-        fn as_mut_ptr() {
-            let mut a = vec1![22u8, 12, 9];
-            let a_ptr = a.as_mut_ptr();
-            let mut a = a.into_vec();
-            let a_ptr2 = a.as_mut_ptr();
-            assert_eq!(a_ptr, a_ptr2);
+#[cfg(feature = "sqlx-postgres")]
+const _: () = {
+    use sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef},
+        Decode, Encode, Postgres, Type,
+    };
+
+    impl<T> Type<Postgres> for Vec1<T>
+    where
+        T: PgHasArrayType,
+    {
+        fn type_info() -> PgTypeInfo {
+            <Vec<T> as Type<Postgres>>::type_info()
         }
 
-        #[test]
-        fn swap_remove() {
-            let mut a = vec1![1u8, 2, 4];
-            a.swap_remove(0).unwrap();
-            assert_eq!(a, &[4u8, 2]);
-            a.swap_remove(0).unwrap();
-            assert_eq!(a, &[2u8]);
-            a.swap_remove(0).unwrap_err();
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <Vec<T> as Type<Postgres>>::compatible(ty)
         }
+    }
 
-        #[test]
-        fn try_swap_remove() {
-            #![allow(deprecated)]
-            let mut a = vec1![1u8, 2, 4];
-            a.try_swap_remove(0).unwrap();
-            assert_eq!(a, &[4u8, 2]);
-            a.try_swap_remove(0).unwrap();
-            assert_eq!(a, &[2u8]);
-            a.try_swap_remove(0).unwrap_err();
+    impl<'q, T> Encode<'q, Postgres> for Vec1<T>
+    where
+        Vec<T>: Encode<'q, Postgres>,
+    {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+            self.0.encode_by_ref(buf)
         }
 
-        #[test]
-        fn insert() {
-            // we only test that it's there as we only
-            // forward to the underlying Vec so this test
-            // is enough
-            let mut a = vec1![9u8, 7, 3];
-            a.insert(1, 22);
-            assert_eq!(a, &[9u8, 22, 7, 3]);
+        fn produces(&self) -> Option<PgTypeInfo> {
+            self.0.produces()
         }
 
-        #[test]
-        fn remove() {
-            // we only test that it's there as we only
-            // forward to the underlying Vec so this test
-            // is enough
-            let mut a = vec1![9u8, 7, 3];
-            a.remove(1).unwrap();
-            assert_eq!(a, &[9u8, 3]);
-            a.remove(1).unwrap();
-            assert_eq!(a, &[9u8]);
-            a.remove(0).unwrap_err();
+        fn size_hint(&self) -> usize {
+            self.0.size_hint()
+        }
+    }
 
-            catch_unwind(|| {
-                let mut a = vec1![9u8, 7, 3];
-                let _ = a.remove(200);
-            })
-            .unwrap_err();
+    /// Decodes the array like `Vec<T>` does but fails if the array is empty.
+    impl<'r, T> Decode<'r, Postgres> for Vec1<T>
+    where
+        Vec<T>: Decode<'r, Postgres>,
+    {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            let vec = <Vec<T> as Decode<'r, Postgres>>::decode(value)?;
+            Ok(Vec1::try_from_vec(vec)?)
         }
+    }
+};
 
-        #[test]
-        fn try_remove() {
-            #![allow(deprecated)]
+#[cfg(feature = "uniffi")]
+const _: () = {
+    use uniffi_core::{derive_ffi_traits, Lift, Lower, MetadataBuffer, RustBuffer, TypeId};
 
-            // we only test that it's there as we only
-            // forward to the underlying Vec so this test
-            // is enough
-            let mut a = vec1![9u8, 7, 3];
-            a.try_remove(1).unwrap();
-            assert_eq!(a, &[9u8, 3]);
-            a.try_remove(1).unwrap();
-            assert_eq!(a, &[9u8]);
-            a.try_remove(0).unwrap_err();
+    unsafe impl<UT, T> Lower<UT> for Vec1<T>
+    where
+        T: Lower<UT>,
+    {
+        type FfiType = RustBuffer;
 
-            // try_remove is inconsistent and panics on out of bounds but e.g. try_split_off doesn't!
-            catch_unwind(|| {
-                let mut a = vec1![9u8, 7, 3];
-                let _ = a.remove(200);
-            })
-            .unwrap_err();
+        fn write(obj: Self, buf: &mut Vec<u8>) {
+            <Vec<T> as Lower<UT>>::write(obj.0, buf)
         }
 
-        #[test]
-        fn retain() {
-            let mut a = vec1![9u8, 7, 3];
-            let Size0Error = a.retain(|_| false).unwrap_err();
-            assert_eq!(a.len(), 1);
-            assert_eq!(a.first(), &3);
+        fn lower(obj: Self) -> RustBuffer {
+            Self::lower_into_rust_buffer(obj)
+        }
+    }
 
-            let mut a = vec1![9u8, 4, 3, 8, 9];
-            a.retain(|v| *v % 2 == 0).unwrap();
+    unsafe impl<UT, T> Lift<UT> for Vec1<T>
+    where
+        T: Lift<UT>,
+    {
+        type FfiType = RustBuffer;
 
-            assert_eq!(a.len(), 2);
-            assert_eq!(a.first(), &4);
-            assert_eq!(a.last(), &8);
+        fn try_read(buf: &mut &[u8]) -> uniffi_core::Result<Self> {
+            let vec = <Vec<T> as Lift<UT>>::try_read(buf)?;
+            Ok(Vec1::try_from_vec(vec)?)
         }
 
-        proptest! {
-            #[test]
-            fn same_behavior_as_vec_except_when_empty(
-                data in (1usize..=20)
-                    .prop_flat_map(|size| prop::collection::vec(any::<bool>(), size..=size))
-            ) {
-                use std::convert::TryFrom;
+        fn try_lift(buf: RustBuffer) -> uniffi_core::Result<Self> {
+            Self::try_lift_from_rust_buffer(buf)
+        }
+    }
 
-                let mut vec = data.clone();
-                let mut vec1 = Vec1::try_from(data).unwrap();
-                let last = *vec1.last();
+    // Foreign bindings see a normal sequence, the length is checked when lifting.
+    impl<UT, T> TypeId<UT> for Vec1<T>
+    where
+        T: TypeId<UT>,
+    {
+        const TYPE_ID_META: MetadataBuffer = <Vec<T> as TypeId<UT>>::TYPE_ID_META;
+    }
 
-                vec.retain(|value| *value == true);
-                let res = vec1.retain(|value| *value == true);
+    derive_ffi_traits!(impl<T, UT> LowerReturn<UT> for Vec1<T> where Vec1<T>: Lower<UT>);
+    derive_ffi_traits!(impl<T, UT> LowerError<UT> for Vec1<T> where Vec1<T>: Lower<UT>);
+    derive_ffi_traits!(impl<T, UT> LiftReturn<UT> for Vec1<T> where Vec1<T>: Lift<UT>);
+    derive_ffi_traits!(impl<T, UT> LiftRef<UT> for Vec1<T> where Vec1<T>: Lift<UT>);
+};
 
-                if vec.is_empty() {
-                    assert_eq!(res, Err(Size0Error));
-                    assert_eq!(vec1.len(), 1);
-                    assert_eq!(vec1.last(), &last);
-                } else {
-                    assert_eq!(res, Ok(()));
-                    assert_eq!(&*vec, &*vec1);
-                }
+/// Error returned when converting a `js_sys::Array` into a `Vec1` fails.
+#[cfg(feature = "wasm")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum TryFromJsArrayError {
+    /// The array was empty.
+    Empty,
+    /// An element of the array had the wrong type, the element is returned.
+    InvalidElement(wasm_bindgen::JsValue),
+}
 
+#[cfg(feature = "wasm")]
+impl fmt::Display for TryFromJsArrayError {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TryFromJsArrayError::Empty => fmt::Display::fmt(&Size0Error, fter),
+            TryFromJsArrayError::InvalidElement(_) => {
+                fter.write_str("Array element has an unexpected type.")
             }
         }
+    }
+}
 
-        #[test]
-        fn dedup_by_key() {
-            let mut a = vec1![0xA3u16, 0x10F, 0x20F];
-            a.dedup_by_key(|f| *f & 0xFF);
-            assert_eq!(a, &[0xA3, 0x10F]);
+#[cfg(feature = "wasm")]
+impl Error for TryFromJsArrayError {}
+
+#[cfg(feature = "wasm")]
+const _: () = {
+    use js_sys::Array;
+    use wasm_bindgen::{JsCast, JsValue};
+
+    impl<T> From<Vec1<T>> for Array
+    where
+        T: Into<JsValue>,
+    {
+        fn from(vec: Vec1<T>) -> Self {
+            vec.into_iter().map(Into::<JsValue>::into).collect()
         }
+    }
 
-        #[test]
-        fn dedup_by() {
-            let mut a = vec1![1u8, 7u8, 12u8, 10u8];
-            a.dedup_by(|l, r| (*l % 2 == 0) == (*r % 2 == 0));
-            assert_eq!(a, &[1u8, 12u8]);
+    impl<T> TryFrom<Array> for Vec1<T>
+    where
+        T: JsCast,
+    {
+        type Error = TryFromJsArrayError;
+
+        /// Converts the array checking that it's not empty and that each element is a `T`.
+        ///
+        /// Emptiness is checked first, so no element is converted for an empty array.
+        fn try_from(array: Array) -> Result<Self, Self::Error> {
+            if array.length() == 0 {
+                return Err(TryFromJsArrayError::Empty);
+            }
+            let vec = array
+                .iter()
+                .map(|value| value.dyn_into::<T>())
+                .collect::<Result<Vec<T>, JsValue>>()
+                .map_err(TryFromJsArrayError::InvalidElement)?;
+            Vec1::try_from_vec(vec).map_err(|_| TryFromJsArrayError::Empty)
         }
+    }
+};
 
-        #[test]
-        fn push() {
-            let mut a = vec1![1u8, 2, 10];
-            a.push(1);
-            assert_eq!(a, &[1u8, 2, 10, 1]);
+#[cfg(feature = "im")]
+const _: () = {
+    impl<T> From<Vec1<T>> for im::Vector<T>
+    where
+        T: Clone,
+    {
+        fn from(vec: Vec1<T>) -> Self {
+            vec.into_vec().into()
         }
+    }
 
-        #[test]
-        fn pop() {
-            let mut a = vec1![3u8, 10, 2];
-            a.pop().unwrap();
-            assert_eq!(a, &[3u8, 10]);
-            a.pop().unwrap();
-            assert_eq!(a, &[3u8]);
-            a.pop().unwrap_err();
+    impl<T> TryFrom<im::Vector<T>> for Vec1<T>
+    where
+        T: Clone,
+    {
+        type Error = Size0Error;
+
+        fn try_from(vector: im::Vector<T>) -> Result<Self, Self::Error> {
+            Vec1::try_from_iter(vector)
         }
+    }
+};
 
-        #[test]
-        fn try_pop() {
-            #![allow(deprecated)]
+#[cfg(feature = "indexmap")]
+const _: () = {
+    use core::hash::{BuildHasher, Hash};
+    use indexmap::{IndexMap, IndexSet};
 
-            let mut a = vec1![3u8, 10, 2];
-            a.try_pop().unwrap();
-            assert_eq!(a, &[3u8, 10]);
-            a.try_pop().unwrap();
-            assert_eq!(a, &[3u8]);
-            a.try_pop().unwrap_err();
+    impl<T, S> TryFrom<IndexSet<T, S>> for Vec1<T> {
+        type Error = Size0Error;
+
+        /// Keeps the insertion order of the set.
+        fn try_from(set: IndexSet<T, S>) -> Result<Self, Self::Error> {
+            Vec1::try_from_iter(set)
         }
+    }
 
-        #[test]
-        fn append() {
-            let mut a = vec1![9u8, 12, 93];
-            a.append(&mut std::vec![33, 12]);
-            assert_eq!(a, &[9u8, 12, 93, 33, 12]);
+    impl<K, V, S> TryFrom<IndexMap<K, V, S>> for Vec1<(K, V)> {
+        type Error = Size0Error;
+
+        /// Keeps the insertion order of the map.
+        fn try_from(map: IndexMap<K, V, S>) -> Result<Self, Self::Error> {
+            Vec1::try_from_iter(map)
         }
+    }
 
-        macro_rules! do_call_drain {
-            ($vec:ident.drain($from:expr, $to:expr, $incl:expr) => $iter:ident => $map:block) => {{
-                match ($from, $to) {
-                    (Some(from), Some(to)) => {
-                        if $incl {
-                            let $iter = $vec.drain(from..=to);
-                            $map
-                        } else {
-                            let $iter = $vec.drain(from..to);
-                            $map
-                        }
-                    }
-                    (None, Some(to)) => {
-                        if $incl {
-                            let $iter = $vec.drain(..=to);
-                            $map
-                        } else {
-                            let $iter = $vec.drain(..to);
-                            $map
-                        }
-                    }
-                    (Some(from), None) => {
-                        let $iter = $vec.drain(from..);
-                        $map
-                    }
-                    (None, None) => {
-                        let $iter = $vec.drain(..);
-                        $map
-                    }
-                }
-            }};
+    impl<T, S> From<Vec1<T>> for IndexSet<T, S>
+    where
+        T: Hash + Eq,
+        S: BuildHasher + Default,
+    {
+        /// Duplicates are removed, keeping the position of the first occurrence.
+        fn from(vec: Vec1<T>) -> Self {
+            vec.into_iter().collect()
         }
+    }
 
-        proptest! {
+    impl<K, V, S> From<Vec1<(K, V)>> for IndexMap<K, V, S>
+    where
+        K: Hash + Eq,
+        S: BuildHasher + Default,
+    {
+        /// For duplicate keys the last value wins, but the position of the first occurrence is kept.
+        fn from(vec: Vec1<(K, V)>) -> Self {
+            vec.into_iter().collect()
+        }
+    }
+};
 
-            #[test]
-            fn works_as_in_vec_except_if_draining_all(
-                data in prop::collection::vec(any::<u8>(), 1..=5),
-                start in (0usize..7).prop_map(|v| if v == 7 { None } else { Some(v) }),
-                end in (0usize..7).prop_map(|v| if v == 7 { None } else { Some(v) }),
-                incl in any::<bool>()
-            ) {
-                use std::convert::TryFrom;
+#[cfg(feature = "bytes")]
+const _: () = {
+    use bytes::{Bytes, BytesMut};
 
-                let mut data2 = data.clone();
-                let res_vec = catch_unwind(move || {
-                    let drained = do_call_drain!(data2.drain(start, end, incl) => iter => { iter.collect::<Vec<u8>>() });
-                    (data2, drained)
-                });
+    impl From<Vec1<u8>> for Bytes {
+        fn from(vec: Vec1<u8>) -> Self {
+            Bytes::from(vec.into_vec())
+        }
+    }
 
-                let mut data2 = Vec1::try_from(data.clone()).unwrap();
-                let res_vec1 = catch_unwind(move ||{
-                    let drained = do_call_drain!(data2.drain(start, end, incl) => iter_res => { iter_res.map(|iter| iter.collect::<Vec<u8>>()) });
-                    (data2, drained)
-                });
+    impl From<Vec1<u8>> for BytesMut {
+        fn from(vec: Vec1<u8>) -> Self {
+            BytesMut::from(Bytes::from(vec))
+        }
+    }
 
-                match (res_vec, res_vec1) {
-                    (Err(_), Err(_)) => {/*both paniced (out of bounds)*/},
-                    (Ok(_), Err(panic)) => std::panic::resume_unwind(panic),
-                    (Err(panic), Ok(_)) => std::panic::resume_unwind(panic),
-                    (Ok((vec, vec_drained)), Ok((vec1, vec1_res))) => {
-                        if vec.is_empty() {
-                            assert_eq!(vec1_res, Err(Size0Error));
-                            assert_eq!(&*vec1, &*data);
-                        } else {
-                            let vec1_drained = vec1_res.unwrap();
-                            assert_eq!(vec_drained, vec1_drained);
-                            assert_eq!(&*vec, &*vec1);
-                        }
-                    }
-                }
+    impl TryFrom<Bytes> for Vec1<u8> {
+        type Error = Size0Error;
+
+        /// Reuses the buffer if the `Bytes` is the unique owner of it, copies otherwise.
+        fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+            if bytes.is_empty() {
+                Err(Size0Error)
+            } else {
+                Ok(Vec1(Vec::from(bytes)))
             }
         }
+    }
 
-        // #[test]
-        // fn clear() {
-        //     //TODO comptest a.clear() must not compile
-        //     let mut a = vec1![1u8,2,3];
-        //     a.clear();
-        // }
+    impl TryFrom<BytesMut> for Vec1<u8> {
+        type Error = Size0Error;
 
-        #[test]
-        fn len() {
-            let a = vec1![12u8, 4, 6, 2, 3];
-            assert_eq!(a.len(), 5);
+        fn try_from(bytes: BytesMut) -> Result<Self, Self::Error> {
+            if bytes.is_empty() {
+                Err(Size0Error)
+            } else {
+                Ok(Vec1(Vec::from(bytes)))
+            }
         }
+    }
+};
 
-        #[test]
-        fn len_nonzero() {
-            let a = vec1![12u8, 4, 6, 2, 3];
-            assert_eq!(a.len_nonzero(), NonZeroUsize::new(5).unwrap());
+#[cfg(feature = "ndarray")]
+const _: () = {
+    use ndarray::Array1;
+
+    impl<T> From<Vec1<T>> for Array1<T> {
+        fn from(vec: Vec1<T>) -> Self {
+            Array1::from_vec(vec.into_vec())
         }
+    }
 
-        #[test]
-        fn is_empty() {
-            let a = vec1![12u8];
-            //we don't impl. it but slice does
-            assert_eq!(a.is_empty(), false);
+    impl<T> TryFrom<Array1<T>> for Vec1<T> {
+        type Error = Size0Error;
+
+        /// Elements are moved out in logical order, independent of the memory layout of the array.
+        fn try_from(array: Array1<T>) -> Result<Self, Self::Error> {
+            Vec1::try_from_iter(array)
         }
+    }
+};
 
-        #[test]
-        fn split_off() {
-            let mut left = vec1![88u8, 73, 12, 6];
-            let mut right = left.split_off(1).unwrap();
-            assert_eq!(left, &[88u8]);
-            assert_eq!(right, &[73u8, 12, 6]);
+#[cfg(feature = "triomphe")]
+const _: () = {
+    use triomphe::{Arc, ThinArc};
 
-            right.split_off(0).unwrap_err();
-            right.split_off(right.len()).unwrap_err();
+    impl<T> From<Vec1<T>> for Arc<[T]> {
+        fn from(vec: Vec1<T>) -> Self {
+            Arc::from(vec.into_vec())
+        }
+    }
 
-            catch_unwind(|| {
-                let mut v = vec1![1u8, 3, 4];
-                let _ = v.split_off(200);
-            })
-            .unwrap_err();
+    impl<T> From<Vec1<T>> for ThinArc<(), T> {
+        fn from(vec: Vec1<T>) -> Self {
+            ThinArc::from_header_and_iter((), vec.into_iter())
+        }
+    }
+};
+
+#[cfg(feature = "ecow")]
+const _: () = {
+    use ecow::EcoVec;
+
+    impl<T> From<Vec1<T>> for EcoVec<T>
+    where
+        T: Clone,
+    {
+        fn from(vec: Vec1<T>) -> Self {
+            EcoVec::from(vec.into_vec())
         }
+    }
 
-        #[test]
-        fn try_split_off() {
-            #![allow(deprecated)]
+    impl<T> TryFrom<EcoVec<T>> for Vec1<T>
+    where
+        T: Clone,
+    {
+        type Error = Size0Error;
 
-            let mut left = vec1![88u8, 73, 12, 6];
-            let mut right = left.try_split_off(1).unwrap();
-            assert_eq!(left, &[88u8]);
-            assert_eq!(right, &[73u8, 12, 6]);
+        /// Moves the elements out if the `EcoVec` is the unique owner of them, clones them otherwise.
+        fn try_from(vec: EcoVec<T>) -> Result<Self, Self::Error> {
+            Vec1::try_from_iter(vec)
+        }
+    }
+};
 
-            right.try_split_off(0).unwrap_err();
-            right.try_split_off(right.len()).unwrap_err();
+#[cfg(feature = "serde_with")]
+const _: () = {
+    use ::serde::{de::Error as _, Deserializer, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
 
-            // Also returns `Size0Error` on out of bounds.
-            let Size0Error = right.try_split_off(200).unwrap_err();
+    impl<T, U> SerializeAs<Vec1<T>> for Vec1<U>
+    where
+        U: SerializeAs<T>,
+    {
+        fn serialize_as<S: Serializer>(source: &Vec1<T>, serializer: S) -> Result<S::Ok, S::Error> {
+            <Vec<U> as SerializeAs<Vec<T>>>::serialize_as(source.as_vec(), serializer)
         }
+    }
 
-        #[test]
-        fn resize_with() {
-            let mut a = vec1![1u8];
-            a.resize_with(3, || 3u8).unwrap();
-            assert_eq!(a, &[1u8, 3, 3]);
-            a.resize_with(0, || 0u8).unwrap_err();
+    impl<'de, T, U> DeserializeAs<'de, Vec1<T>> for Vec1<U>
+    where
+        U: DeserializeAs<'de, T>,
+    {
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Vec1<T>, D::Error> {
+            let vec = <Vec<U> as DeserializeAs<'de, Vec<T>>>::deserialize_as(deserializer)?;
+            Vec1::try_from_vec(vec).map_err(D::Error::custom)
         }
+    }
+};
 
-        #[test]
-        fn try_resize_with() {
-            #![allow(deprecated)]
+#[cfg(feature = "miniserde")]
+const _: () = {
+    use miniserde::{
+        de::{Deserialize, Seq, Visitor},
+        make_place,
+        ser::{Fragment, Serialize},
+        Error, Result,
+    };
 
-            let mut a = vec1![1u8];
-            a.try_resize_with(3, || 3u8).unwrap();
-            assert_eq!(a, &[1u8, 3, 3]);
-            a.try_resize_with(0, || 0u8).unwrap_err();
+    impl<T> Serialize for Vec1<T>
+    where
+        T: Serialize,
+    {
+        fn begin(&self) -> Fragment<'_> {
+            self.0.begin()
         }
+    }
 
-        #[test]
-        fn leak() {
-            let a = vec1![1u8, 3];
-            let s: &'static mut [u8] = a.leak();
-            assert_eq!(s, &[1u8, 3]);
+    make_place!(Place);
+
+    impl<T> Deserialize for Vec1<T>
+    where
+        T: Deserialize,
+    {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            Place::new(out)
         }
+    }
 
-        #[test]
-        fn resize() {
-            let mut a = vec1![1u8, 2];
-            a.resize(4, 19).unwrap();
-            assert_eq!(a, &[1u8, 2, 19, 19]);
-            a.resize(0, 19).unwrap_err();
+    impl<T> Visitor for Place<Vec1<T>>
+    where
+        T: Deserialize,
+    {
+        fn seq(&mut self) -> Result<Box<dyn Seq + '_>> {
+            Ok(Box::new(Vec1SeqBuilder {
+                out: &mut self.out,
+                vec: Vec::new(),
+                element: None,
+            }))
         }
+    }
 
-        #[test]
-        fn try_resize() {
-            #![allow(deprecated)]
+    struct Vec1SeqBuilder<'a, T> {
+        out: &'a mut Option<Vec1<T>>,
+        vec: Vec<T>,
+        element: Option<T>,
+    }
 
-            let mut a = vec1![1u8, 2];
-            a.try_resize(4, 19).unwrap();
-            assert_eq!(a, &[1u8, 2, 19, 19]);
-            a.try_resize(0, 19).unwrap_err();
+    impl<T> Vec1SeqBuilder<'_, T> {
+        fn shift(&mut self) {
+            if let Some(element) = self.element.take() {
+                self.vec.push(element);
+            }
         }
+    }
 
-        #[test]
-        fn extend_from_slice() {
-            let mut a = vec1![1u8];
-            a.extend_from_slice(&[2u8, 3, 4]);
-            assert_eq!(a, &[1u8, 2, 3, 4]);
+    impl<T> Seq for Vec1SeqBuilder<'_, T>
+    where
+        T: Deserialize,
+    {
+        fn element(&mut self) -> Result<&mut dyn Visitor> {
+            self.shift();
+            Ok(Deserialize::begin(&mut self.element))
         }
 
-        #[test]
-        fn dedup() {
-            let mut a = vec1![1u8, 1, 2, 2];
-            a.dedup();
-            assert_eq!(a, &[1u8, 2]);
+        fn finish(&mut self) -> Result<()> {
+            self.shift();
+            let vec = core::mem::take(&mut self.vec);
+            *self.out = Some(Vec1::try_from_vec(vec).map_err(|_| Error)?);
+            Ok(())
         }
+    }
+};
 
-        #[test]
-        fn splice() {
-            let mut a = vec1![1u8, 2, 3, 4];
+#[cfg(feature = "postgres")]
+const _: () = {
+    use bytes::BytesMut;
+    use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
 
-            let out: Vec<u8> = a.splice(1..3, std::vec![11, 12, 13]).unwrap().collect();
-            assert_eq!(a, &[1u8, 11, 12, 13, 4]);
-            assert_eq!(out, &[2u8, 3]);
+    type BoxDynError = Box<dyn Error + Sync + Send>;
 
-            let out: Vec<u8> = a.splice(2.., std::vec![7, 8]).unwrap().collect();
-            assert_eq!(a, &[1u8, 11, 7, 8]);
-            assert_eq!(out, &[12u8, 13, 4]);
+    impl<T> ToSql for Vec1<T>
+    where
+        T: ToSql,
+    {
+        fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxDynError> {
+            self.0.to_sql(ty, out)
+        }
 
-            let out: Vec<u8> = a.splice(..2, std::vec![100, 200]).unwrap().collect();
-            assert_eq!(a, &[100u8, 200, 7, 8]);
-            assert_eq!(out, &[1u8, 11]);
+        fn accepts(ty: &Type) -> bool {
+            <Vec<T> as ToSql>::accepts(ty)
+        }
 
-            let out: Vec<u8> = a.splice(.., std::vec![10, 220]).unwrap().collect();
-            assert_eq!(a, &[10u8, 220]);
-            assert_eq!(out, &[100u8, 200, 7, 8]);
+        to_sql_checked!();
+    }
 
-            let out: Vec<u8> = a.splice(1.., Vec::<u8>::new()).unwrap().collect();
-            assert_eq!(a, &[10u8]);
-            assert_eq!(out, &[220u8]);
+    /// Reads the array like `Vec<T>` does but fails if the array is empty.
+    impl<'a, T> FromSql<'a> for Vec1<T>
+    where
+        T: FromSql<'a>,
+    {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxDynError> {
+            let vec = <Vec<T> as FromSql<'a>>::from_sql(ty, raw)?;
+            Ok(Vec1::try_from_vec(vec)?)
+        }
 
-            a.splice(.., Vec::<u8>::new()).unwrap_err();
+        fn accepts(ty: &Type) -> bool {
+            <Vec<T> as FromSql<'a>>::accepts(ty)
+        }
+    }
+};
 
-            assert!(catch_unwind(|| {
-                let mut a = vec1![1u8, 2];
-                let _ = a.splice(1..0, std::vec![]);
-            })
-            .is_err());
+#[cfg(feature = "diesel")]
+const _: () = {
+    use diesel::{
+        deserialize::{self, FromSql},
+        expression::AsExpression,
+        pg::{Pg, PgValue},
+        serialize::{self, Output, ToSql},
+        sql_types::{Array, Nullable},
+    };
+
+    // Binds the inner `Vec`, so the bound expression is the same as for `Vec<T>`.
+    macro_rules! vec1_as_expression {
+        ($ty:ty, $inner:ty, $sql_type:ty, |$this:ident| $to_inner:expr) => {
+            impl<'a, ST: 'static, T> AsExpression<$sql_type> for $ty {
+                type Expression = <$inner as AsExpression<$sql_type>>::Expression;
+
+                fn as_expression(self) -> Self::Expression {
+                    let $this = self;
+                    <$inner as AsExpression<$sql_type>>::as_expression($to_inner)
+                }
+            }
+        };
+    }
 
-            assert!(catch_unwind(|| {
-                let mut a = vec1![1u8, 2];
-                let _ = a.splice(3.., std::vec![]);
-            })
-            .is_err());
+    vec1_as_expression!(Vec1<T>, Vec<T>, Array<ST>, |v| v.0);
+    vec1_as_expression!(Vec1<T>, Vec<T>, Nullable<Array<ST>>, |v| v.0);
+    vec1_as_expression!(&'a Vec1<T>, &'a Vec<T>, Array<ST>, |v| &v.0);
+    vec1_as_expression!(&'a Vec1<T>, &'a Vec<T>, Nullable<Array<ST>>, |v| &v.0);
 
-            assert!(catch_unwind(|| {
-                let mut a = vec1![1u8, 2];
-                let _ = a.splice(..3, std::vec![]);
+    impl<ST, T> ToSql<Array<ST>, Pg> for Vec1<T>
+    where
+        ST: 'static,
+        [T]: ToSql<Array<ST>, Pg>,
+        T: fmt::Debug,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            ToSql::<Array<ST>, Pg>::to_sql(self.as_slice(), out)
+        }
+    }
+
+    impl<ST, T> ToSql<Nullable<Array<ST>>, Pg> for Vec1<T>
+    where
+        ST: 'static,
+        Vec1<T>: ToSql<Array<ST>, Pg>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            ToSql::<Array<ST>, Pg>::to_sql(self, out)
+        }
+    }
+
+    /// Reads the array like `Vec<T>` does but fails if the array is empty.
+    impl<ST, T> FromSql<Array<ST>, Pg> for Vec1<T>
+    where
+        T: FromSql<ST, Pg>,
+    {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            let vec = <Vec<T> as FromSql<Array<ST>, Pg>>::from_sql(value)?;
+            Ok(Vec1::try_from_vec(vec)?)
+        }
+    }
+};
+
+#[cfg(feature = "juniper")]
+const _: () = {
+    use juniper::{
+        macros::reflect::{BaseSubTypes, BaseType, Type, Types, WrappedType},
+        marker::{IsInputType, IsOutputType},
+        meta::MetaType,
+        BoxFuture, ExecutionResult, Executor, FieldError, FromInputValue, GraphQLType,
+        GraphQLValue, GraphQLValueAsync, InputValue, IntoFieldError, Registry, ScalarValue,
+        Selection, ToInputValue,
+    };
+
+    impl<S, T> GraphQLType<S> for Vec1<T>
+    where
+        T: GraphQLType<S>,
+        S: ScalarValue,
+    {
+        fn name(info: &Self::TypeInfo) -> Option<&str> {
+            <Vec<T> as GraphQLType<S>>::name(info)
+        }
+
+        fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+        where
+            S: 'r,
+        {
+            <Vec<T> as GraphQLType<S>>::meta(info, registry)
+        }
+    }
+
+    impl<S, T> GraphQLValue<S> for Vec1<T>
+    where
+        T: GraphQLValue<S>,
+        S: ScalarValue,
+    {
+        type Context = T::Context;
+        type TypeInfo = T::TypeInfo;
+
+        fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+            self.0.type_name(info)
+        }
+
+        fn resolve(
+            &self,
+            info: &Self::TypeInfo,
+            selection_set: Option<&[Selection<S>]>,
+            executor: &Executor<Self::Context, S>,
+        ) -> ExecutionResult<S> {
+            self.0.resolve(info, selection_set, executor)
+        }
+    }
+
+    impl<S, T> GraphQLValueAsync<S> for Vec1<T>
+    where
+        T: GraphQLValueAsync<S>,
+        T::TypeInfo: Sync,
+        T::Context: Sync,
+        S: ScalarValue + Send + Sync,
+    {
+        fn resolve_async<'a>(
+            &'a self,
+            info: &'a Self::TypeInfo,
+            selection_set: Option<&'a [Selection<S>]>,
+            executor: &'a Executor<Self::Context, S>,
+        ) -> BoxFuture<'a, ExecutionResult<S>> {
+            self.0.resolve_async(info, selection_set, executor)
+        }
+    }
+
+    /// Converts the input like `Vec<T>` does but fails if the list is empty.
+    impl<S, T> FromInputValue<S> for Vec1<T>
+    where
+        T: FromInputValue<S>,
+        T::Error: IntoFieldError<S>,
+        S: ScalarValue,
+    {
+        type Error = FieldError<S>;
+
+        fn from_input_value(value: &InputValue<S>) -> Result<Self, Self::Error> {
+            let vec = <Vec<T> as FromInputValue<S>>::from_input_value(value)
+                .map_err(IntoFieldError::into_field_error)?;
+            Ok(Vec1::try_from_vec(vec)?)
+        }
+    }
+
+    impl<S, T> ToInputValue<S> for Vec1<T>
+    where
+        T: ToInputValue<S>,
+        S: ScalarValue,
+    {
+        fn to_input_value(&self) -> InputValue<S> {
+            self.0.to_input_value()
+        }
+    }
+
+    impl<S, T> IsInputType<S> for Vec1<T>
+    where
+        T: IsInputType<S>,
+        S: ScalarValue,
+    {
+        fn mark() {
+            T::mark()
+        }
+    }
+
+    impl<S, T> IsOutputType<S> for Vec1<T>
+    where
+        T: IsOutputType<S>,
+        S: ScalarValue,
+    {
+        fn mark() {
+            T::mark()
+        }
+    }
+
+    impl<S, T> BaseType<S> for Vec1<T>
+    where
+        T: BaseType<S>,
+    {
+        const NAME: Type = T::NAME;
+    }
+
+    impl<S, T> BaseSubTypes<S> for Vec1<T>
+    where
+        T: BaseSubTypes<S>,
+    {
+        const NAMES: Types = T::NAMES;
+    }
+
+    impl<S, T> WrappedType<S> for Vec1<T>
+    where
+        T: WrappedType<S>,
+    {
+        const VALUE: u128 = <Vec<T> as WrappedType<S>>::VALUE;
+    }
+};
+
+#[cfg(feature = "rocket")]
+const _: () = {
+    use rocket::form::{self, error::ErrorKind, DataField, Errors, FromForm, Options, ValueField};
+
+    /// Parses the values like `Vec<T>` does but requires at least one value.
+    ///
+    /// If the field is missing a `Missing` error is reported for it, like for any
+    /// other required field.
+    #[rocket::async_trait]
+    impl<'v, T> FromForm<'v> for Vec1<T>
+    where
+        T: FromForm<'v> + 'v,
+    {
+        type Context = <Vec<T> as FromForm<'v>>::Context;
+
+        fn init(opts: Options) -> Self::Context {
+            <Vec<T> as FromForm<'v>>::init(opts)
+        }
+
+        fn push_value(ctxt: &mut Self::Context, field: ValueField<'v>) {
+            <Vec<T> as FromForm<'v>>::push_value(ctxt, field)
+        }
+
+        async fn push_data(ctxt: &mut Self::Context, field: DataField<'v, '_>) {
+            <Vec<T> as FromForm<'v>>::push_data(ctxt, field).await
+        }
+
+        fn push_error(ctxt: &mut Self::Context, error: form::Error<'v>) {
+            <Vec<T> as FromForm<'v>>::push_error(ctxt, error)
+        }
+
+        fn finalize(ctxt: Self::Context) -> form::Result<'v, Self> {
+            let vec = <Vec<T> as FromForm<'v>>::finalize(ctxt)?;
+            Vec1::try_from_vec(vec).map_err(|err| {
+                Errors::from(ErrorKind::Validation(
+                    std::string::ToString::to_string(&err).into(),
+                ))
             })
-            .is_err());
         }
 
+        fn default(_opts: Options) -> Option<Self> {
+            None
+        }
+    }
+};
+
+#[cfg(feature = "redis")]
+const _: () = {
+    use redis::{
+        ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value,
+    };
+
+    impl<T> ToRedisArgs for Vec1<T>
+    where
+        T: ToRedisArgs,
+    {
+        fn write_redis_args<W>(&self, out: &mut W)
+        where
+            W: ?Sized + RedisWrite,
+        {
+            self.0.write_redis_args(out)
+        }
+
+        fn is_single_arg(&self) -> bool {
+            self.0.is_single_arg()
+        }
+    }
+
+    fn empty_response_error() -> RedisError {
+        RedisError::from((
+            ErrorKind::TypeError,
+            "Response was of incompatible type",
+            "expected at least 1 element, got an empty response".into(),
+        ))
+    }
+
+    impl<T> FromRedisValue for Vec1<T>
+    where
+        T: FromRedisValue,
+    {
+        fn from_redis_value(value: &Value) -> RedisResult<Self> {
+            Vec1::try_from_vec(Vec::from_redis_value(value)?).map_err(|_| empty_response_error())
+        }
+
+        fn from_owned_redis_value(value: Value) -> RedisResult<Self> {
+            Vec1::try_from_vec(Vec::from_owned_redis_value(value)?)
+                .map_err(|_| empty_response_error())
+        }
+    }
+};
+
+#[cfg(test)]
+mod test {
+    #![allow(non_snake_case)]
+    // Older tests which are written in a style some newer clippy lints dislike.
+    #![allow(
+        clippy::bool_assert_comparison,
+        clippy::bool_comparison,
+        clippy::reversed_empty_ranges,
+        clippy::unused_io_amount,
+        clippy::useless_conversion
+    )]
+
+    mod Size0Error {
+        #![allow(non_snake_case)]
+        use super::super::*;
+        use std::error::Error as StdError;
+
         #[test]
-        fn first() {
-            let a = vec1![12u8, 13];
-            assert_eq!(a.first(), &12u8);
+        fn implements_std_error() {
+            fn comp_check<T: StdError>() {}
+            comp_check::<Size0Error>();
+        }
+    }
+
+    mod Vec1 {
+        use core::num::NonZeroUsize;
+        use std::panic::catch_unwind;
+
+        use super::super::*;
+        use ::proptest::prelude::*;
+
+        // prevent a type from causing us to use the wrong type
+        #[allow(unused_macros)]
+        macro_rules! vec {
+            ($($any:tt)*) => {
+                compile_error!("typo? vec! => vec1!")
+            };
         }
 
         #[test]
-        fn first_mut() {
-            let mut a = vec1![12u8, 13];
-            assert_eq!(a.first_mut(), &mut 12u8);
+        fn new_vec1_macro() {
+            let a = vec1![1u8, 10u8, 3u8];
+            assert_eq!(a, &[1, 10, 3]);
+
+            let a = vec1![40u8];
+            assert_eq!(a, &[40]);
+
+            //TODO comptest vec1![] => compiler error
         }
 
         #[test]
-        fn last() {
-            let a = vec1![12u8, 13];
-            assert_eq!(a.last(), &13u8);
+        fn new() {
+            let a = Vec1::new(1u8);
+            assert_eq!(a.len(), 1);
+            assert_eq!(a.first(), &1u8);
         }
 
         #[test]
-        fn last_mut() {
-            let mut a = vec1![12u8, 13];
-            assert_eq!(a.last_mut(), &mut 13u8);
+        fn with_capacity() {
+            let a = Vec1::with_capacity(2u8, 10);
+            assert_eq!(a.len(), 1);
+            assert_eq!(a.first(), &2u8);
+            assert_eq!(a.capacity(), 10);
         }
 
         #[test]
-        fn split_off_last() {
-            let a = vec1![12u8, 33, 44];
-            let (heads, last): (Vec<u8>, u8) = a.split_off_last();
-            assert_eq!(heads, &[12u8, 33]);
-            assert_eq!(last, 44);
+        fn capacity() {
+            let a = Vec1::with_capacity(2u8, 123);
+            assert_eq!(a.capacity(), 123);
         }
 
         #[test]
-        fn split_off_first() {
-            let a = vec1![12u8, 33, 45];
-            let (first, tail): (u8, Vec<u8>) = a.split_off_first();
-            assert_eq!(tail, &[33u8, 45]);
-            assert_eq!(first, 12);
+        fn reserve() {
+            let mut a = Vec1::with_capacity(1u8, 1);
+            assert_eq!(a.capacity(), 1);
+            a.reserve(15);
+            assert!(a.capacity() > 10);
         }
 
         #[test]
-        fn from_vec_push() {
-            assert_eq!(Vec1::from_vec_push(std::vec![], 1u8), vec1![1]);
-            assert_eq!(Vec1::from_vec_push(std::vec![1, 2], 3u8), vec1![1, 2, 3]);
+        fn reserve_exact() {
+            let mut a = Vec1::with_capacity(1u8, 1);
+            assert_eq!(a.capacity(), 1);
+            a.reserve_exact(11);
+            assert_eq!(a.capacity(), 12);
+        }
+
+        #[test]
+        fn shrink_to_fit() {
+            let mut a = Vec1::with_capacity(1u8, 20);
+            a.push(13u8);
+            a.shrink_to_fit();
+            assert_eq!(a.capacity(), 2);
+        }
+
+        #[test]
+        fn into_boxed_slice() {
+            let a = vec1![32u8, 12u8];
+            let boxed: Box<[u8]> = a.into_boxed_slice();
+            assert_eq!(&*boxed, &[32u8, 12u8]);
+        }
+
+        #[test]
+        fn truncate() {
+            let mut a = vec1![42u8, 32, 1];
+            a.truncate(1).unwrap();
+            assert_eq!(a.len(), 1);
+            assert_eq!(a, &[42u8]);
+
+            a.truncate(0).unwrap_err();
+        }
+
+        #[test]
+        fn try_truncate() {
+            #![allow(deprecated)]
+
+            let mut a = vec1![42u8, 32, 1];
+            a.try_truncate(1).unwrap();
+            assert_eq!(a.len(), 1);
+            assert_eq!(a, &[42u8]);
+
+            a.try_truncate(0).unwrap_err();
+        }
+
+        #[test]
+        fn as_slice() {
+            let a = vec1![22u8, 12, 9];
+            let b: &[u8] = a.as_slice();
+            assert_eq!(b, &[22u8, 12, 9]);
+        }
+
+        #[test]
+        fn as_mut_slice() {
+            let mut a = vec1![22u8, 12, 9];
+            let b: &mut [u8] = a.as_mut_slice();
+            assert_eq!(b, &mut [22u8, 12, 9]);
+        }
+
+        #[test]
+        fn as_slice1() {
+            let mut a = vec1![22u8, 12, 9];
+            assert_eq!(a.as_slice1().first(), &22);
+            *a.as_mut_slice1().last_mut() = 10;
+            let b: &Slice1<u8> = a.as_ref();
+            assert_eq!(b, &[22u8, 12, 10]);
+        }
+
+        #[test]
+        fn as_ptr() {
+            let a = vec1![22u8, 12, 9];
+            let a_ptr = a.as_ptr();
+            let a = a.into_vec();
+            let a_ptr2 = a.as_ptr();
+            assert_eq!(a_ptr, a_ptr2);
+        }
+
+        #[test]
+        fn as_mut_ptr() {
+            let mut a = vec1![22u8, 12, 9];
+            let a_ptr = a.as_mut_ptr();
+            let mut a = a.into_vec();
+            let a_ptr2 = a.as_mut_ptr();
+            assert_eq!(a_ptr, a_ptr2);
+        }
+
+        #[test]
+        fn swap_remove() {
+            let mut a = vec1![1u8, 2, 4];
+            a.swap_remove(0).unwrap();
+            assert_eq!(a, &[4u8, 2]);
+            a.swap_remove(0).unwrap();
+            assert_eq!(a, &[2u8]);
+            a.swap_remove(0).unwrap_err();
+        }
+
+        #[test]
+        fn pop_many() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            assert_eq!(a.pop_many(0), Ok(Vec::new()));
+            assert_eq!(a.pop_many(2), Ok(std::vec![3u8, 4]));
+            assert_eq!(a.pop_many(2), Err(Size0Error));
+            assert_eq!(a, &[1u8, 2]);
+            assert_eq!(a.pop_many(1), Ok(std::vec![2u8]));
+        }
+
+        #[test]
+        fn swap_remove_front() {
+            let mut a = vec1![1u8, 2, 4];
+            assert_eq!(a.swap_remove_front(), Ok(1));
+            assert_eq!(a, &[4u8, 2]);
+            assert_eq!(a.swap_remove_front(), Ok(4));
+            assert_eq!(a.swap_remove_front(), Err(Size0Error));
+            assert_eq!(a, &[2u8]);
+        }
+
+        #[test]
+        fn try_swap_remove() {
+            #![allow(deprecated)]
+            let mut a = vec1![1u8, 2, 4];
+            a.try_swap_remove(0).unwrap();
+            assert_eq!(a, &[4u8, 2]);
+            a.try_swap_remove(0).unwrap();
+            assert_eq!(a, &[2u8]);
+            a.try_swap_remove(0).unwrap_err();
+        }
+
+        #[test]
+        fn insert() {
+            // we only test that it's there as we only
+            // forward to the underlying Vec so this test
+            // is enough
+            let mut a = vec1![9u8, 7, 3];
+            a.insert(1, 22);
+            assert_eq!(a, &[9u8, 22, 7, 3]);
+        }
+
+        #[test]
+        fn remove() {
+            // we only test that it's there as we only
+            // forward to the underlying Vec so this test
+            // is enough
+            let mut a = vec1![9u8, 7, 3];
+            a.remove(1).unwrap();
+            assert_eq!(a, &[9u8, 3]);
+            a.remove(1).unwrap();
+            assert_eq!(a, &[9u8]);
+            a.remove(0).unwrap_err();
+
+            catch_unwind(|| {
+                let mut a = vec1![9u8, 7, 3];
+                let _ = a.remove(200);
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        fn try_remove() {
+            #![allow(deprecated)]
+
+            // we only test that it's there as we only
+            // forward to the underlying Vec so this test
+            // is enough
+            let mut a = vec1![9u8, 7, 3];
+            a.try_remove(1).unwrap();
+            assert_eq!(a, &[9u8, 3]);
+            a.try_remove(1).unwrap();
+            assert_eq!(a, &[9u8]);
+            a.try_remove(0).unwrap_err();
+
+            // try_remove is inconsistent and panics on out of bounds but e.g. try_split_off doesn't!
+            catch_unwind(|| {
+                let mut a = vec1![9u8, 7, 3];
+                let _ = a.remove(200);
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        fn retain() {
+            let mut a = vec1![9u8, 7, 3];
+            let Size0Error = a.retain(|_| false).unwrap_err();
+            assert_eq!(a.len(), 1);
+            assert_eq!(a.first(), &3);
+
+            let mut a = vec1![9u8, 4, 3, 8, 9];
+            a.retain(|v| *v % 2 == 0).unwrap();
+
+            assert_eq!(a.len(), 2);
+            assert_eq!(a.first(), &4);
+            assert_eq!(a.last(), &8);
+        }
+
+        proptest! {
+            #[test]
+            fn same_behavior_as_vec_except_when_empty(
+                data in (1usize..=20)
+                    .prop_flat_map(|size| prop::collection::vec(any::<bool>(), size..=size))
+            ) {
+                use std::convert::TryFrom;
+
+                let mut vec = data.clone();
+                let mut vec1 = Vec1::try_from(data).unwrap();
+                let last = *vec1.last();
+
+                vec.retain(|value| *value == true);
+                let res = vec1.retain(|value| *value == true);
+
+                if vec.is_empty() {
+                    assert_eq!(res, Err(Size0Error));
+                    assert_eq!(vec1.len(), 1);
+                    assert_eq!(vec1.last(), &last);
+                } else {
+                    assert_eq!(res, Ok(()));
+                    assert_eq!(&*vec, &*vec1);
+                }
+
+            }
+        }
+
+        #[test]
+        fn edit() {
+            let mut a = vec1![1u8, 2, 3];
+            let sum = a.edit(|vec| {
+                vec.retain(|v| *v != 2);
+                vec.iter().sum::<u8>()
+            });
+            assert_eq!(sum, Ok(4));
+            assert_eq!(a, [1u8, 3]);
+
+            assert_eq!(a.edit(|vec| vec.clear()), Err(Size0Error));
+            assert_eq!(a, [0u8]);
+            assert_eq!(a.edit_or_else(|vec| vec.clear(), || 7), Err(Size0Error));
+            assert_eq!(a, [7u8]);
+        }
+
+        #[test]
+        fn edit_restores_on_panic() {
+            let mut a = vec1![1u8, 2, 3];
+            catch_unwind(std::panic::AssertUnwindSafe(|| {
+                a.edit_or_else(
+                    |vec| {
+                        vec.clear();
+                        panic!("failed to refill");
+                    },
+                    || 9,
+                )
+            }))
+            .unwrap_err();
+            assert_eq!(a, [9u8]);
+        }
+
+        #[test]
+        fn raw_parts() {
+            let mut a = vec1![1u8, 2, 3];
+            a.reserve(5);
+            let (ptr, length, capacity) = a.into_raw_parts();
+            assert_eq!(length.get(), 3);
+            assert!(capacity >= 8);
+            let a = unsafe { Vec1::from_raw_parts(ptr, length, capacity) };
+            assert_eq!(a, [1u8, 2, 3]);
+            assert_eq!(a.capacity(), capacity);
+            let a = unsafe { Vec1::from_vec_unchecked(a.into_vec()) };
+            assert_eq!(a, [1u8, 2, 3]);
+        }
+
+        #[test]
+        fn dedup_by_key() {
+            let mut a = vec1![0xA3u16, 0x10F, 0x20F];
+            a.dedup_by_key(|f| *f & 0xFF);
+            assert_eq!(a, &[0xA3, 0x10F]);
+        }
+
+        #[test]
+        fn dedup_by() {
+            let mut a = vec1![1u8, 7u8, 12u8, 10u8];
+            a.dedup_by(|l, r| (*l % 2 == 0) == (*r % 2 == 0));
+            assert_eq!(a, &[1u8, 12u8]);
+        }
+
+        #[test]
+        fn try_map_in_place() {
+            let mut a = vec1![1u8, 2, 3];
+            assert_eq!(
+                a.try_map_in_place(|x| {
+                    *x *= 2;
+                    Ok::<_, ()>(())
+                }),
+                Ok(())
+            );
+            assert_eq!(a, &[2u8, 4, 6]);
+            let res = a.try_map_in_place(|x| {
+                if *x > 3 {
+                    return Err(*x);
+                }
+                *x = 0;
+                Ok(())
+            });
+            assert_eq!(res, Err(4));
+            assert_eq!(a, &[0u8, 4, 6]);
+        }
+
+        #[test]
+        fn try_replace_in_place() {
+            let mut a = vec1![1u8, 2, 3];
+            assert_eq!(a.try_replace_in_place(|x| Ok::<_, ()>(x + 1)), Ok(()));
+            assert_eq!(a, &[2u8, 3, 4]);
+            let res = a.try_replace_in_place(|x| if *x > 2 { Err(*x) } else { Ok(0) });
+            assert_eq!(res, Err(3));
+            assert_eq!(a, &[0u8, 3, 4]);
+        }
+
+        #[test]
+        fn scan1() {
+            let a = vec1![1u8, 2, 3];
+            assert_eq!(
+                a.scan1(|x| *x as u32, |acc, x| acc * 10 + *x as u32),
+                vec1![1u32, 12, 123]
+            );
+            assert_eq!(vec1![5u8].scan1(|x| *x, |_, _| unreachable!()), vec1![5]);
+        }
+
+        #[test]
+        fn join1() {
+            let a = vec1![vec1![1u8], vec1![2, 3], vec1![4]];
+            assert_eq!(a.join1(&0), vec1![1u8, 0, 2, 3, 0, 4]);
+            assert_eq!(a.concat1(), vec1![1u8, 2, 3, 4]);
+            assert_eq!(vec1![vec1![1u8]].join1(&0), vec1![1u8]);
+        }
+
+        #[test]
+        fn coalesce() {
+            let a = vec1![(1u8, 1u8), (2, 1), (2, 1), (3, 1), (3, 1), (3, 1)];
+            let merged = a.coalesce(|l, r| {
+                if l.0 == r.0 {
+                    Ok((l.0, l.1 + r.1))
+                } else {
+                    Err((l, r))
+                }
+            });
+            assert_eq!(merged, vec1![(1u8, 1u8), (2, 2), (3, 3)]);
+            let single = vec1![1u8].coalesce(|_, _| unreachable!());
+            assert_eq!(single, vec1![1u8]);
+        }
+
+        #[test]
+        fn remove_indices() {
+            let mut a = vec1![0u8, 1, 2, 3, 4, 5];
+            a.remove_indices(&[]).unwrap();
+            a.remove_indices(&[1, 2, 5]).unwrap();
+            assert_eq!(a, &[0u8, 3, 4]);
+            assert_eq!(a.remove_indices(&[0, 1, 2]), Err(Size0Error));
+            assert_eq!(a, &[0u8, 3, 4]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn remove_indices_panics_on_unsorted_indices() {
+            let mut a = vec1![0u8, 1, 2, 3];
+            let _ = a.remove_indices(&[2, 1]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn remove_indices_panics_on_out_of_bounds_index() {
+            let mut a = vec1![0u8, 1, 2, 3];
+            let _ = a.remove_indices(&[1, 4]);
+        }
+
+        #[test]
+        fn pairs() {
+            let a = vec1![1u8, 2, 4];
+            let pairs: Vec<_> = a.pairs().collect();
+            assert_eq!(pairs, std::vec![(&1u8, &2u8), (&2, &4)]);
+            assert_eq!(a.pairs().len(), 2);
+            assert_eq!(vec1![1u8].pairs().next(), None);
+        }
+
+        #[test]
+        fn get_clamped() {
+            let mut a = vec1![1u8, 2, 4];
+            assert_eq!(a.get_clamped(0), &1);
+            assert_eq!(a.get_clamped(2), &4);
+            assert_eq!(a.get_clamped(usize::MAX), &4);
+            *a.get_clamped_mut(7) = 5;
+            assert_eq!(a, &[1u8, 2, 5]);
+        }
+
+        #[test]
+        fn ends() {
+            let mut a = vec1![1u8, 2, 3];
+            assert_eq!(a.ends(), (&1, &3));
+            let (first, last) = a.ends_mut();
+            *first = 10;
+            *last.unwrap() = 30;
+            assert_eq!(a, &[10u8, 2, 30]);
+            let mut a = vec1![1u8];
+            assert_eq!(a.ends(), (&1, &1));
+            assert_eq!(a.ends_mut(), (&mut 1, None));
+        }
+
+        #[test]
+        fn builder_combinators() {
+            let a = vec1![3u8].pushed(1).appended(std::vec![2]).sorted();
+            assert_eq!(a, vec1![1u8, 2, 3]);
+            assert_eq!(a.reversed(), vec1![3u8, 2, 1]);
+        }
+
+        #[test]
+        fn max_and_min() {
+            let a = vec1![(2u8, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+            assert_eq!(a.max_element(), &(2, 'c'));
+            assert_eq!(a.min_element(), &(1, 'b'));
+            assert_eq!(a.max_by_key(|v| v.0), &(2, 'c'));
+            assert_eq!(a.min_by_key(|v| v.0), &(1, 'b'));
+            assert_eq!(a.max_by(|l, r| l.0.cmp(&r.0)), &(2, 'c'));
+            assert_eq!(a.min_by(|l, r| l.0.cmp(&r.0)), &(1, 'b'));
+        }
+
+        #[test]
+        fn min_set_and_max_set() {
+            let a = vec1![(2u8, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+            assert_eq!(a.min_set(), vec1![&(0, 'e')]);
+            assert_eq!(a.max_set_by_key(|v| v.0), vec1![&(2, 'a'), &(2, 'c')]);
+            assert_eq!(
+                a.min_set_by_key(|v| v.0 % 2),
+                vec1![&(2, 'a'), &(2, 'c'), &(0, 'e')]
+            );
+            assert_eq!(
+                a.max_set_by(|l, r| (l.0 % 2).cmp(&(r.0 % 2))),
+                vec1![&(1, 'b'), &(1, 'd')]
+            );
+            assert_eq!(vec1![1u8].max_set(), vec1![&1]);
+        }
+
+        #[test]
+        fn sort_and_dedup() {
+            let mut a = vec1![3u8, 1, 3, 2, 1];
+            a.sort_and_dedup().push(4);
+            assert_eq!(a, &[1u8, 2, 3, 4]);
+        }
+
+        #[test]
+        fn unique() {
+            let mut a = vec1![3u8, 1, 3, 2, 1, 3];
+            a.unique();
+            assert_eq!(a, &[3u8, 1, 2]);
+        }
+
+        #[test]
+        fn unique_by_key() {
+            let mut a = vec1!["b", "aa", "c", "dd", "eee"];
+            a.unique_by_key(|s| s.len());
+            assert_eq!(a, &["b", "aa", "eee"]);
+        }
+
+        #[test]
+        fn sort_stable_and_dedup() {
+            #[derive(Debug, Clone, Copy)]
+            struct ByKey(u8, char);
+            impl core::cmp::PartialEq for ByKey {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            }
+            impl core::cmp::Eq for ByKey {}
+            impl core::cmp::PartialOrd for ByKey {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+            impl core::cmp::Ord for ByKey {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.0.cmp(&other.0)
+                }
+            }
+
+            let mut a = vec1![ByKey(2, 'a'), ByKey(1, 'b'), ByKey(2, 'c'), ByKey(1, 'd')];
+            a.sort_stable_and_dedup();
+            let chars = a.iter().map(|k| k.1).collect::<Vec<_>>();
+            assert_eq!(chars, ['b', 'a']);
+        }
+
+        #[test]
+        fn push() {
+            let mut a = vec1![1u8, 2, 10];
+            a.push(1);
+            assert_eq!(a, &[1u8, 2, 10, 1]);
+        }
+
+        #[test]
+        fn pop() {
+            let mut a = vec1![3u8, 10, 2];
+            a.pop().unwrap();
+            assert_eq!(a, &[3u8, 10]);
+            a.pop().unwrap();
+            assert_eq!(a, &[3u8]);
+            a.pop().unwrap_err();
+        }
+
+        #[test]
+        fn try_pop() {
+            #![allow(deprecated)]
+
+            let mut a = vec1![3u8, 10, 2];
+            a.try_pop().unwrap();
+            assert_eq!(a, &[3u8, 10]);
+            a.try_pop().unwrap();
+            assert_eq!(a, &[3u8]);
+            a.try_pop().unwrap_err();
+        }
+
+        #[test]
+        fn append() {
+            let mut a = vec1![9u8, 12, 93];
+            a.append(&mut std::vec![33, 12]);
+            assert_eq!(a, &[9u8, 12, 93, 33, 12]);
+        }
+
+        macro_rules! do_call_drain {
+            ($vec:ident.drain($from:expr, $to:expr, $incl:expr) => $iter:ident => $map:block) => {{
+                match ($from, $to) {
+                    (Some(from), Some(to)) => {
+                        if $incl {
+                            let $iter = $vec.drain(from..=to);
+                            $map
+                        } else {
+                            let $iter = $vec.drain(from..to);
+                            $map
+                        }
+                    }
+                    (None, Some(to)) => {
+                        if $incl {
+                            let $iter = $vec.drain(..=to);
+                            $map
+                        } else {
+                            let $iter = $vec.drain(..to);
+                            $map
+                        }
+                    }
+                    (Some(from), None) => {
+                        let $iter = $vec.drain(from..);
+                        $map
+                    }
+                    (None, None) => {
+                        let $iter = $vec.drain(..);
+                        $map
+                    }
+                }
+            }};
+        }
+
+        proptest! {
+
+            #[test]
+            fn works_as_in_vec_except_if_draining_all(
+                data in prop::collection::vec(any::<u8>(), 1..=5),
+                start in (0usize..7).prop_map(|v| if v == 7 { None } else { Some(v) }),
+                end in (0usize..7).prop_map(|v| if v == 7 { None } else { Some(v) }),
+                incl in any::<bool>()
+            ) {
+                use std::convert::TryFrom;
+
+                let mut data2 = data.clone();
+                let res_vec = catch_unwind(move || {
+                    let drained = do_call_drain!(data2.drain(start, end, incl) => iter => { iter.collect::<Vec<u8>>() });
+                    (data2, drained)
+                });
+
+                let mut data2 = Vec1::try_from(data.clone()).unwrap();
+                let res_vec1 = catch_unwind(move ||{
+                    let drained = do_call_drain!(data2.drain(start, end, incl) => iter_res => { iter_res.map(|iter| iter.collect::<Vec<u8>>()) });
+                    (data2, drained)
+                });
+
+                match (res_vec, res_vec1) {
+                    (Err(_), Err(_)) => {/*both paniced (out of bounds)*/},
+                    (Ok(_), Err(panic)) => std::panic::resume_unwind(panic),
+                    (Err(panic), Ok(_)) => std::panic::resume_unwind(panic),
+                    (Ok((vec, vec_drained)), Ok((vec1, vec1_res))) => {
+                        if vec.is_empty() {
+                            assert_eq!(vec1_res, Err(Size0Error));
+                            assert_eq!(&*vec1, &*data);
+                        } else {
+                            let vec1_drained = vec1_res.unwrap();
+                            assert_eq!(vec_drained, vec1_drained);
+                            assert_eq!(&*vec, &*vec1);
+                        }
+                    }
+                }
+            }
+        }
+
+        // #[test]
+        // fn clear() {
+        //     //TODO comptest a.clear() must not compile
+        //     let mut a = vec1![1u8,2,3];
+        //     a.clear();
+        // }
+
+        #[test]
+        fn len() {
+            let a = vec1![12u8, 4, 6, 2, 3];
+            assert_eq!(a.len(), 5);
+        }
+
+        #[test]
+        fn len_nonzero() {
+            let a = vec1![12u8, 4, 6, 2, 3];
+            assert_eq!(a.len_nonzero(), NonZeroUsize::new(5).unwrap());
+        }
+
+        #[test]
+        fn is_empty() {
+            let a = vec1![12u8];
+            //we don't impl. it but slice does
+            assert_eq!(a.is_empty(), false);
+        }
+
+        #[test]
+        fn insert_many() {
+            let mut a = vec1![1u8, 4];
+            a.insert_many(1, [2, 3]);
+            assert_eq!(a, &[1u8, 2, 3, 4]);
+            a.insert_many(4, Some(5));
+            a.insert_many(0, None);
+            assert_eq!(a, &[1u8, 2, 3, 4, 5]);
+
+            catch_unwind(|| {
+                let mut a = vec1![1u8];
+                a.insert_many(2, [3]);
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        fn splice1() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            let out: Vec<u8> = a.splice1(1..3, vec1![11]).collect();
+            assert_eq!(a, &[1u8, 11, 4]);
+            assert_eq!(out, &[2u8, 3]);
+
+            let out: Vec<u8> = a.splice1(.., vec1![7]).collect();
+            assert_eq!(a, &[7u8]);
+            assert_eq!(out, &[1u8, 11, 4]);
+
+            assert!(catch_unwind(|| {
+                let mut a = vec1![1u8, 2];
+                let _ = a.splice1(1..3, vec1![3]);
+            })
+            .is_err());
+        }
+
+        #[test]
+        fn split_off() {
+            let mut left = vec1![88u8, 73, 12, 6];
+            let mut right = left.split_off(1).unwrap();
+            assert_eq!(left, &[88u8]);
+            assert_eq!(right, &[73u8, 12, 6]);
+
+            right.split_off(0).unwrap_err();
+            right.split_off(right.len()).unwrap_err();
+
+            catch_unwind(|| {
+                let mut v = vec1![1u8, 3, 4];
+                let _ = v.split_off(200);
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        fn first_chunk() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.first_chunk::<2>(), Some(&[1u8, 2]));
+            assert_eq!(vec.first_chunk::<3>(), Some(&[1u8, 2, 3]));
+            assert_eq!(vec.first_chunk::<4>(), None);
+            vec.first_chunk_mut::<2>().unwrap()[1] = 20;
+            assert_eq!(vec, vec1![1, 20, 3]);
+            assert_eq!(vec.first_as_array(), &[1]);
+        }
+
+        #[test]
+        fn last_chunk() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.last_chunk::<2>(), Some(&[2u8, 3]));
+            assert_eq!(vec.last_chunk::<0>(), Some(&[]));
+            assert_eq!(vec.last_chunk::<4>(), None);
+            vec.last_chunk_mut::<2>().unwrap()[0] = 20;
+            assert_eq!(vec, vec1![1, 20, 3]);
+            assert_eq!(vec.last_as_array(), &[3]);
+        }
+
+        #[test]
+        fn first_and_rest() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.first_and_rest(), (&1, &[2u8, 3] as &[u8]));
+            let (first, rest) = vec.first_and_rest_mut();
+            *first = 10;
+            rest[0] = 20;
+            assert_eq!(vec, vec1![10, 20, 3]);
+
+            let vec = vec1![1u8];
+            assert_eq!(vec.first_and_rest(), (&1, &[] as &[u8]));
+        }
+
+        #[test]
+        fn rest_and_last() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.rest_and_last(), (&[1u8, 2] as &[u8], &3));
+            let (rest, last) = vec.rest_and_last_mut();
+            *last = 30;
+            rest[1] = 20;
+            assert_eq!(vec, vec1![1, 20, 30]);
+        }
+
+        #[test]
+        fn split_off_rest() {
+            let mut left = vec1![88u8, 73, 12, 6];
+            let right = left.split_off_rest(NonZeroUsize::new(1).unwrap());
+            assert_eq!(left, &[88u8]);
+            assert_eq!(right, &[73u8, 12, 6]);
+
+            let right = left.split_off_rest(NonZeroUsize::new(1).unwrap());
+            assert_eq!(left, &[88u8]);
+            assert!(right.is_empty());
+
+            catch_unwind(|| {
+                let mut v = vec1![1u8, 3, 4];
+                let _ = v.split_off_rest(NonZeroUsize::new(4).unwrap());
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        fn try_split_off() {
+            #![allow(deprecated)]
+
+            let mut left = vec1![88u8, 73, 12, 6];
+            let mut right = left.try_split_off(1).unwrap();
+            assert_eq!(left, &[88u8]);
+            assert_eq!(right, &[73u8, 12, 6]);
+
+            right.try_split_off(0).unwrap_err();
+            right.try_split_off(right.len()).unwrap_err();
+
+            // Also returns `Size0Error` on out of bounds.
+            let Size0Error = right.try_split_off(200).unwrap_err();
+        }
+
+        #[test]
+        fn resize_with() {
+            let mut a = vec1![1u8];
+            a.resize_with(3, || 3u8).unwrap();
+            assert_eq!(a, &[1u8, 3, 3]);
+            a.resize_with(0, || 0u8).unwrap_err();
+        }
+
+        #[test]
+        fn try_resize_with() {
+            #![allow(deprecated)]
+
+            let mut a = vec1![1u8];
+            a.try_resize_with(3, || 3u8).unwrap();
+            assert_eq!(a, &[1u8, 3, 3]);
+            a.try_resize_with(0, || 0u8).unwrap_err();
+        }
+
+        #[test]
+        fn leak() {
+            let a = vec1![1u8, 3];
+            let s: &'static mut [u8] = a.leak();
+            assert_eq!(s, &[1u8, 3]);
+        }
+
+        #[test]
+        fn leak1() {
+            let a = vec1![1u8, 3];
+            let s: &'static mut crate::Slice1<u8> = a.leak1();
+            assert_eq!(s.first(), &1);
+            assert_eq!(s, &[1u8, 3]);
+        }
+
+        #[test]
+        fn resize() {
+            let mut a = vec1![1u8, 2];
+            a.resize(4, 19).unwrap();
+            assert_eq!(a, &[1u8, 2, 19, 19]);
+            a.resize(0, 19).unwrap_err();
+        }
+
+        #[test]
+        fn try_resize() {
+            #![allow(deprecated)]
+
+            let mut a = vec1![1u8, 2];
+            a.try_resize(4, 19).unwrap();
+            assert_eq!(a, &[1u8, 2, 19, 19]);
+            a.try_resize(0, 19).unwrap_err();
+        }
+
+        #[test]
+        fn extend_from_slice() {
+            let mut a = vec1![1u8];
+            a.extend_from_slice(&[2u8, 3, 4]);
+            assert_eq!(a, &[1u8, 2, 3, 4]);
+        }
+
+        #[test]
+        fn dedup() {
+            let mut a = vec1![1u8, 1, 2, 2];
+            a.dedup();
+            assert_eq!(a, &[1u8, 2]);
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn splice() {
+            let mut a = vec1![1u8, 2, 3, 4];
+
+            let out: Vec<u8> = a.splice(1..3, std::vec![11, 12, 13]).unwrap().collect();
+            assert_eq!(a, &[1u8, 11, 12, 13, 4]);
+            assert_eq!(out, &[2u8, 3]);
+
+            let out: Vec<u8> = a.splice(2.., std::vec![7, 8]).unwrap().collect();
+            assert_eq!(a, &[1u8, 11, 7, 8]);
+            assert_eq!(out, &[12u8, 13, 4]);
+
+            let out: Vec<u8> = a.splice(..2, std::vec![100, 200]).unwrap().collect();
+            assert_eq!(a, &[100u8, 200, 7, 8]);
+            assert_eq!(out, &[1u8, 11]);
+
+            let out: Vec<u8> = a.splice(.., std::vec![10, 220]).unwrap().collect();
+            assert_eq!(a, &[10u8, 220]);
+            assert_eq!(out, &[100u8, 200, 7, 8]);
+
+            let out: Vec<u8> = a.splice(1.., Vec::<u8>::new()).unwrap().collect();
+            assert_eq!(a, &[10u8]);
+            assert_eq!(out, &[220u8]);
+
+            a.splice(.., Vec::<u8>::new()).unwrap_err();
+
+            assert!(catch_unwind(|| {
+                let mut a = vec1![1u8, 2];
+                let _ = a.splice(1..0, std::vec![]);
+            })
+            .is_err());
+
+            assert!(catch_unwind(|| {
+                let mut a = vec1![1u8, 2];
+                let _ = a.splice(3.., std::vec![]);
+            })
+            .is_err());
+
+            assert!(catch_unwind(|| {
+                let mut a = vec1![1u8, 2];
+                let _ = a.splice(..3, std::vec![]);
+            })
+            .is_err());
+        }
+
+        #[test]
+        fn first() {
+            let a = vec1![12u8, 13];
+            assert_eq!(a.first(), &12u8);
+        }
+
+        #[test]
+        fn first_mut() {
+            let mut a = vec1![12u8, 13];
+            assert_eq!(a.first_mut(), &mut 12u8);
+        }
+
+        #[test]
+        fn last() {
+            let a = vec1![12u8, 13];
+            assert_eq!(a.last(), &13u8);
+        }
+
+        #[test]
+        fn last_mut() {
+            let mut a = vec1![12u8, 13];
+            assert_eq!(a.last_mut(), &mut 13u8);
+        }
+
+        #[test]
+        fn split_off_last() {
+            let a = vec1![12u8, 33, 44];
+            let (heads, last): (Vec<u8>, u8) = a.split_off_last();
+            assert_eq!(heads, &[12u8, 33]);
+            assert_eq!(last, 44);
+        }
+
+        #[test]
+        fn split_off_first() {
+            let a = vec1![12u8, 33, 45];
+            let (first, tail): (u8, Vec<u8>) = a.split_off_first();
+            assert_eq!(tail, &[33u8, 45]);
+            assert_eq!(first, 12);
+        }
+
+        #[test]
+        fn from_vec_push() {
+            assert_eq!(Vec1::from_vec_push(std::vec![], 1u8), vec1![1]);
+            assert_eq!(Vec1::from_vec_push(std::vec![1, 2], 3u8), vec1![1, 2, 3]);
+        }
+
+        #[test]
+        fn from_vec_insert() {
+            assert_eq!(Vec1::from_vec_insert(std::vec![], 0, 1u8), vec1![1]);
+            assert_eq!(
+                Vec1::from_vec_insert(std::vec![1, 3], 1, 2u8),
+                vec1![1, 2, 3]
+            );
+            assert!(catch_unwind(|| {
+                Vec1::from_vec_insert(std::vec![1, 3], 3, 2u8);
+            })
+            .is_err());
+        }
+
+        #[test]
+        fn reduce() {
+            assert_eq!(vec1![1u8, 2, 4, 3].reduce(std::cmp::max), 4);
+            assert_eq!(vec1![1u8, 2, 2, 3].reduce(|a, b| a + b), 8);
+        }
+
+        #[test]
+        fn reduce_ref() {
+            let a = vec1![std::cell::Cell::new(4)];
+            a.reduce_ref(std::cmp::max).set(44);
+            assert_eq!(a, vec1![std::cell::Cell::new(44)]);
+        }
+
+        #[test]
+        fn reduce_mut() {
+            let mut a = vec1![1u8, 2, 4, 3];
+            *a.reduce_mut(std::cmp::max) *= 2;
+            assert_eq!(a, vec1![1u8, 2, 8, 3]);
+        }
+
+        #[test]
+        fn try_reserve() {
+            let mut a = vec1![1u8, 2, 4, 3];
+            a.try_reserve(100).unwrap();
+            assert!(a.capacity() > 100);
+            a.try_reserve(usize::MAX).unwrap_err();
+        }
+
+        #[test]
+        fn try_reserve_exact() {
+            let mut a = vec1![1u8, 2, 4, 3];
+            a.try_reserve_exact(124).unwrap();
+            assert_eq!(a.capacity(), 128);
+            a.try_reserve(usize::MAX).unwrap_err();
+        }
+
+        #[test]
+        fn shrink_to() {
+            let mut a = Vec1::with_capacity(1, 16);
+            a.extend([2, 3, 4]);
+            a.shrink_to(16);
+            assert_eq!(a.capacity(), 16);
+            a.shrink_to(4);
+            assert_eq!(a.capacity(), 4);
+            a.shrink_to(1);
+            assert_eq!(a.capacity(), 4);
+        }
+
+        #[test]
+        fn spare_capacity_mut() {
+            let mut a = Vec1::with_capacity(1, 16);
+            a.extend([2, 3, 4]);
+            assert_eq!(a.spare_capacity_mut().len(), 12);
+        }
+
+        #[test]
+        fn extend_from_within() {
+            let mut a = vec1!["a", "b", "c", "d"];
+            a.extend_from_within(1..3);
+            assert_eq!(a, ["a", "b", "c", "d", "b", "c"]);
+        }
+
+        mod AsMut {
+            use crate::*;
+
+            #[test]
+            fn of_slice() {
+                let mut a = vec1![33u8, 123];
+                let s: &mut [u8] = a.as_mut();
+                assert_eq!(s, &mut [33u8, 123]);
+            }
+
+            #[test]
+            fn of_self() {
+                let mut a = vec1![33u8, 123];
+                let v: &mut Vec1<u8> = a.as_mut();
+                assert_eq!(v, &mut vec1![33u8, 123]);
+            }
+
+            //TODO comptest AsMut of Vec must not compile
+        }
+
+        mod AsRef {
+            use crate::*;
+
+            #[test]
+            fn of_slice() {
+                let a = vec1![32u8, 103];
+                let s: &[u8] = a.as_ref();
+                assert_eq!(s, &[32u8, 103]);
+            }
+
+            #[test]
+            fn of_vec() {
+                let a = vec1![33u8];
+                let v: &Vec<u8> = a.as_ref();
+                assert_eq!(v, &std::vec![33u8]);
+            }
+
+            #[test]
+            fn of_self() {
+                let a = vec1![211u8];
+                let v: &Vec1<u8> = a.as_ref();
+                assert_eq!(v, &vec1![211u8]);
+            }
+        }
+
+        mod Borrow {
+            use core::borrow::Borrow as _;
+
+            use crate::*;
+
+            #[test]
+            fn of_slice() {
+                let a = vec1![32u8, 103];
+                let s: &[u8] = a.borrow();
+                assert_eq!(s, &[32u8, 103]);
+            }
+
+            #[test]
+            fn of_vec() {
+                let a = vec1![33u8];
+                let v: &Vec<u8> = a.borrow();
+                assert_eq!(v, &std::vec![33u8]);
+            }
+        }
+
+        mod BorrowMut {
+            use core::borrow::BorrowMut as _;
+
+            use crate::*;
+
+            #[test]
+            fn of_slice() {
+                let mut a = vec1![32u8, 103];
+                let s: &mut [u8] = a.borrow_mut();
+                assert_eq!(s, &mut [32u8, 103]);
+            }
+        }
+
+        mod Clone {
+            #[test]
+            fn clone() {
+                let a = vec1![41u8, 12, 33];
+                let b = a.clone();
+                assert_eq!(a, b);
+            }
+        }
+
+        mod Debug {
+            #[test]
+            fn fmt() {
+                let a = vec1![2u8, 3, 1];
+                assert_eq!(std::format!("{:?}", a), "[2, 3, 1]");
+            }
+        }
+
+        #[cfg(not(feature = "disable-default-impl"))]
+        mod Default {
+            use crate::*;
+
+            #[test]
+            fn default() {
+                let a = Vec1::<u8>::default();
+                assert_eq!(a, &[0u8]);
+            }
+        }
+
+        mod Deref {
+            use core::ops::Deref;
+
+            use crate::*;
+
+            #[test]
+            fn deref() {
+                let a = vec1![99, 73];
+                let d: &[u8] = <Vec1<u8> as Deref>::deref(&a);
+                assert_eq!(d, &[99, 73]);
+            }
+        }
+
+        mod DerefMut {
+            use core::ops::DerefMut;
+
+            use crate::*;
+
+            #[test]
+            fn deref() {
+                let mut a = vec1![99, 73];
+                let d: &mut [u8] = <Vec1<u8> as DerefMut>::deref_mut(&mut a);
+                assert_eq!(d, &mut [99, 73]);
+            }
+        }
+
+        mod Eq {
+            use crate::*;
+
+            #[test]
+            fn eq() {
+                let a = vec1![41u8, 12, 33];
+                let b = a.clone();
+                assert_eq!(a, b);
+
+                fn impls_eq<A: Eq>() {}
+                impls_eq::<Vec1<u8>>();
+            }
+        }
+
+        mod Extend {
+            use std::borrow::ToOwned;
+
+            #[test]
+            fn by_value_ref() {
+                let mut a = vec1![0];
+                a.extend(vec1![33u8].iter());
+                assert_eq!(a, &[0, 33]);
+            }
+
+            #[test]
+            fn by_value() {
+                let mut a = vec1!["hy".to_owned()];
+                a.extend(vec1!["ho".to_owned()].into_iter());
+                assert_eq!(a, &["hy".to_owned(), "ho".to_owned()]);
+            }
+        }
+
+        mod TryFrom {
+            use crate::*;
+            use std::{borrow::ToOwned, convert::TryFrom};
+
+            #[test]
+            fn from_slice_ref() {
+                let slice: &[String] = &["hy".to_owned()];
+                let vec = Vec1::try_from(slice).unwrap();
+                assert_eq!(vec, slice);
+
+                let slice: &[String] = &[];
+                Vec1::try_from(slice).unwrap_err();
+            }
+
+            #[test]
+            fn from_slice_mut() {
+                let slice: &mut [String] = &mut ["hy".to_owned()];
+                let vec = Vec1::try_from(&mut *slice).unwrap();
+                assert_eq!(vec, slice);
+
+                let slice: &mut [String] = &mut [];
+                Vec1::try_from(slice).unwrap_err();
+            }
+
+            #[test]
+            fn from_str() {
+                let vec = Vec1::<u8>::try_from("hy").unwrap();
+                assert_eq!(vec, "hy".as_bytes());
+                Vec1::<u8>::try_from("").unwrap_err();
+            }
+
+            #[test]
+            fn from_array() {
+                // we just test if there is a impl for a arbitrary len
+                // which here is good enough but far from complete coverage!
+                let array = [11; 100];
+                let vec = Vec1::try_from(array).unwrap();
+                assert_eq!(vec.iter().sum::<i32>(), 1100);
+
+                Vec1::try_from([0u8; 0]).unwrap_err();
+            }
+
+            #[test]
+            fn from_array_ref() {
+                // we just test if there is a impl for a arbitrary len
+                // which here is good enough but far from complete coverage!
+                let array = [11; 100];
+                let vec = Vec1::try_from(&array).unwrap();
+                assert_eq!(vec.iter().sum::<i32>(), 1100);
+
+                Vec1::try_from([0u8; 0]).unwrap_err();
+            }
+
+            #[test]
+            fn from_array_mut() {
+                // we just test if there is a impl for a arbitrary len
+                // which here is good enough but far from complete coverage!
+                let mut array = [11; 100];
+                let vec = Vec1::try_from(&mut array).unwrap();
+                assert_eq!(vec.iter().sum::<i32>(), 1100);
+
+                Vec1::try_from([0u8; 0]).unwrap_err();
+            }
+
+            #[test]
+            fn from_binary_heap() {
+                use std::collections::BinaryHeap;
+                let mut heap = BinaryHeap::new();
+                heap.push(1u8);
+                heap.push(100);
+                heap.push(3);
+
+                let vec = Vec1::try_from(heap).unwrap();
+                assert_eq!(vec.len(), 3);
+                assert_eq!(vec.first(), &100);
+                assert!(vec.contains(&3));
+                assert!(vec.contains(&1));
+
+                Vec1::<u8>::try_from(BinaryHeap::new()).unwrap_err();
+            }
+
+            #[test]
+            fn from_boxed_slice() {
+                let boxed = Box::new([20u8; 10]) as Box<[u8]>;
+                let vec = Vec1::try_from(boxed).unwrap();
+                assert_eq!(vec, &[20u8; 10]);
+            }
+
+            #[cfg(feature = "std")]
+            #[test]
+            fn from_cstring() {
+                let cstring = CString::new("ABA").unwrap();
+                let vec = Vec1::<u8>::try_from(cstring).unwrap();
+                assert_eq!(vec, &[65, 66, 65]);
+
+                let cstring = CString::new("").unwrap();
+                Vec1::<u8>::try_from(cstring).unwrap_err();
+            }
+
+            #[cfg(feature = "std")]
+            #[test]
+            fn from_cow() {
+                let slice: &[u8] = &[12u8, 33];
+                let cow = Cow::Borrowed(slice);
+                let vec = Vec1::try_from(cow).unwrap();
+                assert_eq!(vec, slice);
+
+                let slice: &[u8] = &[];
+                let cow = Cow::Borrowed(slice);
+                Vec1::try_from(cow).unwrap_err();
+            }
+
+            #[test]
+            fn from_string() {
+                let vec = Vec1::<u8>::try_from("ABA".to_owned()).unwrap();
+                assert_eq!(vec, &[65, 66, 65]);
+
+                Vec1::<u8>::try_from("".to_owned()).unwrap_err();
+            }
+
+            #[test]
+            fn from_vec_deque() {
+                let queue = VecDeque::from(std::vec![1u8, 2, 3]);
+                let vec = Vec1::try_from(queue).unwrap();
+                assert_eq!(vec, &[1u8, 2, 3]);
+
+                Vec1::<u8>::try_from(VecDeque::new()).unwrap_err();
+            }
+        }
+
+        mod Hash {
+            use crate::*;
+            use std::{
+                collections::hash_map::DefaultHasher,
+                hash::{Hash, Hasher},
+            };
+
+            #[test]
+            fn hash() {
+                let a = vec1![1u8, 10, 33, 12];
+                let mut hasher = DefaultHasher::new();
+                a.hash(&mut hasher);
+                let a_state = hasher.finish();
+
+                let b = a.into_vec();
+                let mut hasher = DefaultHasher::new();
+                b.hash(&mut hasher);
+                let b_state = hasher.finish();
+
+                assert_eq!(a_state, b_state);
+            }
+
+            #[test]
+            fn hash_slice() {
+                let a: &[_] = &[vec1![1u8, 10, 33, 12], vec1![22, 12]];
+                let mut hasher = DefaultHasher::new();
+                <Vec1<u8> as Hash>::hash_slice(a, &mut hasher);
+                let a_state = hasher.finish();
+
+                let b: &[_] = &[std::vec![1u8, 10, 33, 12], std::vec![22, 12]];
+                let mut hasher = DefaultHasher::new();
+                <Vec<u8> as Hash>::hash_slice(b, &mut hasher);
+                let b_state = hasher.finish();
+
+                assert_eq!(a_state, b_state);
+            }
+        }
+
+        mod Index {
+            use std::ops::Index;
+
+            #[test]
+            fn index() {
+                let vec = vec1![34u8, 99, 10, 73];
+                assert_eq!(vec.index(1..3), &[99, 10]);
+                assert_eq!(&vec[1..3], &[99, 10]);
+                assert_eq!(vec[0], 34u8);
+            }
+        }
+
+        mod IndexMut {
+            use std::ops::IndexMut;
+
+            #[test]
+            fn index_mut() {
+                let mut vec = vec1![34u8, 99, 10, 73];
+                assert_eq!(vec.index_mut(1..3), &mut [99, 10]);
+                assert_eq!(&mut vec[1..3], &mut [99, 10]);
+            }
+        }
+
+        mod IntoIterator {
+            #[test]
+            fn of_self() {
+                let vec = vec1![1u8, 33u8, 57];
+                let mut iter = vec.into_iter();
+                assert_eq!(iter.size_hint(), (3, Some(3)));
+                // impl. ExactSizedIterator
+                assert_eq!(iter.len(), 3);
+                assert_eq!(iter.next(), Some(1));
+                // impl. DoubleEndedIterator
+                assert_eq!(iter.next_back(), Some(57));
+                assert_eq!(iter.next(), Some(33));
+                assert_eq!(iter.next(), None);
+            }
+
+            #[test]
+            fn of_self_ref() {
+                let vec = vec1![1u8, 33u8, 57];
+                let mut iter = (&vec).into_iter();
+                assert_eq!(iter.size_hint(), (3, Some(3)));
+                // impl. ExactSizedIterator
+                assert_eq!(iter.len(), 3);
+                assert_eq!(iter.next(), Some(&1));
+                // impl. DoubleEndedIterator
+                assert_eq!(iter.next_back(), Some(&57));
+                assert_eq!(iter.next(), Some(&33));
+                assert_eq!(iter.next(), None);
+            }
+
+            #[test]
+            fn of_self_mut() {
+                let mut vec = vec1![1u8, 33u8, 57];
+                let mut iter = (&mut vec).into_iter();
+                assert_eq!(iter.size_hint(), (3, Some(3)));
+                // impl. ExactSizedIterator
+                assert_eq!(iter.len(), 3);
+                assert_eq!(iter.next(), Some(&mut 1));
+                // impl. DoubleEndedIterator
+                assert_eq!(iter.next_back(), Some(&mut 57));
+                assert_eq!(iter.next(), Some(&mut 33));
+                assert_eq!(iter.next(), None);
+            }
+        }
+
+        mod Ord {
+            use std::cmp::Ordering;
+
+            #[test]
+            fn cmp() {
+                // just make sure we implemented it
+                // we will forward to Vec's impl. anyway
+                // so no reasone to test if cmp works correctly
+                // (it doing so is desired sue proptest!).
+                let a = vec1![1u8, 3, 4];
+                let b = vec1![1u8, 4, 2];
+                assert_eq!(a.cmp(&b), Ordering::Less);
+            }
+        }
+
+        mod PartialEq {
+            use crate::*;
+            use std::borrow::ToOwned;
+
+            #[test]
+            fn to_array_ref() {
+                let vec = vec1![67u8, 73, 12];
+                let array: &[u8; 3] = &[67, 73, 12];
+                let array2: &[u8; 3] = &[67, 73, 33];
+                assert_eq!(vec.eq(&array), true);
+                assert_eq!(vec.eq(&array2), false);
+            }
+
+            #[test]
+            fn to_slice_ref() {
+                let vec = vec1![67u8, 73, 12];
+                let array: &[u8] = &[67, 73, 12];
+                let array2: &[u8] = &[67, 73, 33];
+                assert_eq!(vec.eq(&array), true);
+                assert_eq!(vec.eq(&array2), false);
+            }
+
+            #[test]
+            fn to_slice_mut() {
+                let vec = vec1![67u8, 73, 12];
+                let array: &mut [u8] = &mut [67, 73, 12];
+                let array2: &mut [u8] = &mut [67, 73, 33];
+                assert_eq!(vec.eq(&array), true);
+                assert_eq!(vec.eq(&array2), false);
+            }
+
+            #[test]
+            fn to_array() {
+                let vec = vec1![67u8, 73, 12];
+                let array: [u8; 3] = [67, 73, 12];
+                let array2: [u8; 3] = [67, 73, 33];
+                assert_eq!(vec.eq(&array), true);
+                assert_eq!(vec.eq(&array2), false);
+            }
+
+            #[test]
+            fn to_slice() {
+                let vec = vec1![67u8, 73, 12];
+                let array: &[u8] = &[67, 73, 12];
+                let array2: &[u8] = &[67, 73, 33];
+
+                assert_eq!(<Vec1<u8> as PartialEq<[u8]>>::eq(&vec, array), true);
+                assert_eq!(<Vec1<u8> as PartialEq<[u8]>>::eq(&vec, array2), false);
+            }
+
+            #[test]
+            fn to_self_kind() {
+                let a = vec1!["hy".to_owned()];
+                let b = vec1!["hy"];
+                assert_eq!(a, b);
+            }
         }
 
-        #[test]
-        fn from_vec_insert() {
-            assert_eq!(Vec1::from_vec_insert(std::vec![], 0, 1u8), vec1![1]);
-            assert_eq!(
-                Vec1::from_vec_insert(std::vec![1, 3], 1, 2u8),
-                vec1![1, 2, 3]
-            );
-            assert!(catch_unwind(|| {
-                Vec1::from_vec_insert(std::vec![1, 3], 3, 2u8);
-            })
-            .is_err());
-        }
+        mod PartialOrd {
+            use std::cmp::Ordering;
 
-        #[test]
-        fn reduce() {
-            assert_eq!(vec1![1u8, 2, 4, 3].reduce(std::cmp::max), 4);
-            assert_eq!(vec1![1u8, 2, 2, 3].reduce(|a, b| a + b), 8);
+            #[test]
+            fn with_self_kind() {
+                let a = vec1!["b"];
+                let b = vec1!["a"];
+                assert_eq!(a.partial_cmp(&b), Some(Ordering::Greater));
+            }
         }
 
+        #[cfg(feature = "unstable-v2-api")]
         #[test]
-        fn reduce_ref() {
-            let a = vec1![std::cell::Cell::new(4)];
-            a.reduce_ref(std::cmp::max).set(44);
-            assert_eq!(a, vec1![std::cell::Cell::new(44)]);
+        fn try_splice() {
+            let mut a = vec1![1u8, 2, 3, 4];
+            let out: Vec<u8> = a.try_splice(1..3, std::vec![11]).unwrap().collect();
+            assert_eq!(a, &[1u8, 11, 4]);
+            assert_eq!(out, &[2u8, 3]);
+            a.try_splice(.., Vec::<u8>::new()).unwrap_err();
         }
 
         #[test]
-        fn reduce_mut() {
-            let mut a = vec1![1u8, 2, 4, 3];
-            *a.reduce_mut(std::cmp::max) *= 2;
-            assert_eq!(a, vec1![1u8, 2, 8, 3]);
+        fn vec1_macro_repetition() {
+            let vec = vec1![7u8; 3];
+            assert_eq!(vec, &[7u8, 7, 7]);
+            let n = 1;
+            assert_eq!(vec1![String::from("a"); n], vec1![String::from("a")]);
+            catch_unwind(|| {
+                let n = 0;
+                let _ = vec1![1u8; n];
+            })
+            .unwrap_err();
         }
 
         #[test]
-        fn try_reserve() {
-            let mut a = vec1![1u8, 2, 4, 3];
-            a.try_reserve(100).unwrap();
-            assert!(a.capacity() > 100);
-            a.try_reserve(usize::MAX).unwrap_err();
+        fn try_from_elem() {
+            assert_eq!(Vec1::try_from_elem(1u8, 2), Ok(vec1![1, 1]));
+            assert_eq!(Vec1::try_from_elem(1u8, 0), Err(Size0Error));
         }
 
         #[test]
-        fn try_reserve_exact() {
-            let mut a = vec1![1u8, 2, 4, 3];
-            a.try_reserve_exact(124).unwrap();
-            assert_eq!(a.capacity(), 128);
-            a.try_reserve(usize::MAX).unwrap_err();
+        fn non_zero_len_constructors() {
+            let three = NonZeroUsize::new(3).unwrap();
+            assert_eq!(Vec1::from_fn(three, |idx| idx as u8), vec1![0u8, 1, 2]);
+            assert_eq!(Vec1::from_elem(7u8, three), vec1![7u8, 7, 7]);
+            let two = NonZeroUsize::new(2).unwrap();
+            assert_eq!(vec1![1u8, 2].repeat(two), vec1![1u8, 2, 1, 2]);
         }
 
         #[test]
-        fn shrink_to() {
-            let mut a = Vec1::with_capacity(1, 16);
-            a.extend([2, 3, 4]);
-            a.shrink_to(16);
-            assert_eq!(a.capacity(), 16);
-            a.shrink_to(4);
-            assert_eq!(a.capacity(), 4);
-            a.shrink_to(1);
-            assert_eq!(a.capacity(), 4);
+        fn cartesian_product() {
+            let product = vec1![1u8].cartesian_product(&vec1!['a']);
+            assert_eq!(product, vec1![(1u8, 'a')]);
+            let product = vec1![1u8, 2, 3].cartesian_product(&vec1![true, false]);
+            assert_eq!(product.len(), 6);
+            assert_eq!(product.first(), &(1, true));
+            assert_eq!(product[3], (2, false));
+            assert_eq!(product.last(), &(3, false));
         }
 
         #[test]
-        fn spare_capacity_mut() {
-            let mut a = Vec1::with_capacity(1, 16);
-            a.extend([2, 3, 4]);
-            assert_eq!(a.spare_capacity_mut().len(), 12);
+        fn try_from_iter() {
+            let vec = Vec1::try_from_iter(1u8..4).unwrap();
+            assert_eq!(vec, vec1![1, 2, 3]);
+            assert_eq!(Vec1::<u8>::try_from_iter(None), Err(Size0Error));
         }
 
         #[test]
-        fn extend_from_within() {
-            let mut a = vec1!["a", "b", "c", "d"];
-            a.extend_from_within(1..3);
-            assert_eq!(a, ["a", "b", "c", "d", "b", "c"]);
+        fn collect1() {
+            let vec: Vec1<u8> = [1u8, 2].iter().copied().collect1().unwrap();
+            assert_eq!(vec, vec1![1, 2]);
+            assert_eq!(
+                core::iter::empty::<u8>().collect1::<Vec1<u8>>(),
+                Err(Size0Error)
+            );
         }
 
-        mod AsMut {
-            use crate::*;
+        #[cfg(feature = "std")]
+        mod Write {
+            use std::io::Write;
 
             #[test]
-            fn of_slice() {
-                let mut a = vec1![33u8, 123];
-                let s: &mut [u8] = a.as_mut();
-                assert_eq!(s, &mut [33u8, 123]);
+            fn for_bytes() {
+                let mut v = vec1![1u8];
+                v.write(&[65, 100, 12]).unwrap();
+                assert_eq!(v, &[1u8, 65, 100, 12]);
             }
+        }
+
+        #[cfg(feature = "std")]
+        mod Read {
+            use std::{
+                io::{Cursor, Read, Seek, SeekFrom},
+                vec::Vec,
+            };
 
             #[test]
-            fn of_self() {
-                let mut a = vec1![33u8, 123];
-                let v: &mut Vec1<u8> = a.as_mut();
-                assert_eq!(v, &mut vec1![33u8, 123]);
+            fn via_cursor() {
+                let mut cursor = Cursor::new(vec1![1u8, 2, 3]);
+                let mut buf = [0; 2];
+                cursor.read_exact(&mut buf).unwrap();
+                assert_eq!(buf, [1, 2]);
+                cursor.seek(SeekFrom::Start(1)).unwrap();
+                let mut rest = Vec::new();
+                cursor.read_to_end(&mut rest).unwrap();
+                assert_eq!(rest, [2, 3]);
+                assert_eq!(cursor.read(&mut buf).unwrap(), 0);
             }
-
-            //TODO comptest AsMut of Vec must not compile
         }
 
-        mod AsRef {
+        #[cfg(feature = "fake")]
+        mod fake {
             use crate::*;
+            use fake::{Fake, Faker};
 
             #[test]
-            fn of_slice() {
-                let a = vec1![32u8, 103];
-                let s: &[u8] = a.as_ref();
-                assert_eq!(s, &[32u8, 103]);
-            }
-
-            #[test]
-            fn of_vec() {
-                let a = vec1![33u8];
-                let v: &Vec<u8> = a.as_ref();
-                assert_eq!(v, &std::vec![33u8]);
+            fn dummy() {
+                for _ in 0..20 {
+                    let vec: Vec1<u8> = Faker.fake();
+                    assert!(vec.len() < 10);
+                }
             }
 
             #[test]
-            fn of_self() {
-                let a = vec1![211u8];
-                let v: &Vec1<u8> = a.as_ref();
-                assert_eq!(v, &vec1![211u8]);
+            fn dummy_with_len_config() {
+                for _ in 0..20 {
+                    let vec: Vec1<u8> = (Faker, 0..3).fake();
+                    assert!(vec.len() < 3);
+                }
+                let vec: Vec1<u8> = (Faker, 0).fake();
+                assert_eq!(vec.len(), 1);
+                let vec: Vec1<u8> = (Faker, 5).fake();
+                assert_eq!(vec.len(), 5);
             }
         }
 
-        mod Borrow {
-            use core::borrow::Borrow as _;
-
+        #[cfg(feature = "async-graphql")]
+        mod async_graphql {
             use crate::*;
+            use async_graphql::{InputType, OutputType, Value};
 
             #[test]
-            fn of_slice() {
-                let a = vec1![32u8, 103];
-                let s: &[u8] = a.borrow();
-                assert_eq!(s, &[32u8, 103]);
+            fn parse() {
+                let value = Value::List(std::vec![Value::from(1), Value::from(2)]);
+                let vec = <Vec1<i32> as InputType>::parse(Some(value.clone())).unwrap();
+                assert_eq!(vec, vec1![1, 2]);
+                assert_eq!(InputType::to_value(&vec), value);
             }
 
             #[test]
-            fn of_vec() {
-                let a = vec1![33u8];
-                let v: &Vec<u8> = a.borrow();
-                assert_eq!(v, &std::vec![33u8]);
+            fn parse_single_value_as_list() {
+                let vec = <Vec1<i32> as InputType>::parse(Some(Value::from(3))).unwrap();
+                assert_eq!(vec, vec1![3]);
             }
-        }
-
-        mod BorrowMut {
-            use core::borrow::BorrowMut as _;
-
-            use crate::*;
 
             #[test]
-            fn of_slice() {
-                let mut a = vec1![32u8, 103];
-                let s: &mut [u8] = a.borrow_mut();
-                assert_eq!(s, &mut [32u8, 103]);
+            fn parse_empty_list_fails() {
+                <Vec1<i32> as InputType>::parse(Some(Value::List(std::vec![]))).unwrap_err();
             }
-        }
 
-        mod Clone {
             #[test]
-            fn clone() {
-                let a = vec1![41u8, 12, 33];
-                let b = a.clone();
-                assert_eq!(a, b);
+            fn type_name_matches_vec() {
+                assert_eq!(
+                    <Vec1<i32> as InputType>::qualified_type_name(),
+                    <Vec<i32> as InputType>::qualified_type_name()
+                );
+                assert_eq!(
+                    <Vec1<i32> as OutputType>::qualified_type_name(),
+                    <Vec<i32> as OutputType>::qualified_type_name()
+                );
             }
         }
 
-        mod Debug {
+        #[cfg(feature = "ts-rs")]
+        mod ts_rs {
+            use crate::*;
+            use ts_rs::TS;
+
             #[test]
-            fn fmt() {
-                let a = vec1![2u8, 3, 1];
-                assert_eq!(std::format!("{:?}", a), "[2, 3, 1]");
+            fn name() {
+                assert_eq!(Vec1::<u8>::name(), "[number, ...Array<number>]");
+                assert_eq!(Vec1::<u8>::inline(), "[number, ...Array<number>]");
+                assert_eq!(
+                    Vec1::<Option<bool>>::name(),
+                    "[boolean | null, ...Array<boolean | null>]"
+                );
             }
         }
 
-        mod Default {
+        #[cfg(feature = "postgres")]
+        mod postgres {
             use crate::*;
+            use bytes::BytesMut;
+            use postgres_types::{FromSql, ToSql, Type};
 
             #[test]
-            fn default() {
-                let a = Vec1::<u8>::default();
-                assert_eq!(a, &[0u8]);
+            fn roundtrip() {
+                let a = vec1![1i32, 2, 3];
+                let mut buf = BytesMut::new();
+                a.to_sql_checked(&Type::INT4_ARRAY, &mut buf).unwrap();
+                let b = Vec1::<i32>::from_sql(&Type::INT4_ARRAY, &buf).unwrap();
+                assert_eq!(a, b);
             }
-        }
-
-        mod Deref {
-            use core::ops::Deref;
 
-            use crate::*;
+            #[test]
+            fn empty_array_is_rejected() {
+                let mut buf = BytesMut::new();
+                Vec::<i32>::new()
+                    .to_sql(&Type::INT4_ARRAY, &mut buf)
+                    .unwrap();
+                assert!(Vec1::<i32>::from_sql(&Type::INT4_ARRAY, &buf).is_err());
+            }
 
             #[test]
-            fn deref() {
-                let a = vec1![99, 73];
-                let d: &[u8] = <Vec1<u8> as Deref>::deref(&a);
-                assert_eq!(d, &[99, 73]);
+            fn accepts_like_vec() {
+                assert!(<Vec1<i32> as ToSql>::accepts(&Type::INT4_ARRAY));
+                assert!(!<Vec1<i32> as FromSql>::accepts(&Type::INT4));
             }
         }
 
-        mod DerefMut {
-            use core::ops::DerefMut;
-
+        #[cfg(feature = "diesel")]
+        mod diesel {
             use crate::*;
+            use diesel::{
+                deserialize::FromSql,
+                expression::AsExpression,
+                pg::Pg,
+                serialize::ToSql,
+                sql_types::{Array, Integer, Nullable},
+            };
+
+            fn assert_array_mapping<T>()
+            where
+                T: ToSql<Array<Integer>, Pg>
+                    + ToSql<Nullable<Array<Integer>>, Pg>
+                    + FromSql<Array<Integer>, Pg>
+                    + AsExpression<Array<Integer>>
+                    + AsExpression<Nullable<Array<Integer>>>,
+                for<'a> &'a T: AsExpression<Array<Integer>>,
+            {
+            }
 
             #[test]
-            fn deref() {
-                let mut a = vec1![99, 73];
-                let d: &mut [u8] = <Vec1<u8> as DerefMut>::deref_mut(&mut a);
-                assert_eq!(d, &mut [99, 73]);
+            fn implements_array_mapping() {
+                assert_array_mapping::<Vec1<i32>>();
             }
         }
 
-        mod Eq {
+        #[cfg(feature = "redis")]
+        mod redis {
             use crate::*;
+            use redis::{FromRedisValue, ToRedisArgs, Value};
 
             #[test]
-            fn eq() {
-                let a = vec1![41u8, 12, 33];
-                let b = a.clone();
-                assert_eq!(a, b);
+            fn to_redis_args() {
+                let vec = vec1!["a", "bc"];
+                assert_eq!(
+                    vec.to_redis_args(),
+                    std::vec![b"a".to_vec(), b"bc".to_vec()]
+                );
+                assert!(!vec.is_single_arg());
+                assert!(vec1![1u8].is_single_arg());
+            }
 
-                fn impls_eq<A: Eq>() {}
-                impls_eq::<Vec1<u8>>();
+            #[test]
+            fn from_redis_value() {
+                let value = Value::Bulk(std::vec![Value::Int(1), Value::Int(2)]);
+                assert_eq!(Vec1::<u8>::from_redis_value(&value).unwrap(), vec1![1u8, 2]);
+                assert_eq!(
+                    Vec1::<u8>::from_owned_redis_value(value).unwrap(),
+                    vec1![1u8, 2]
+                );
+                Vec1::<u8>::from_redis_value(&Value::Bulk(std::vec![])).unwrap_err();
+                Vec1::<u8>::from_owned_redis_value(Value::Nil).unwrap_err();
             }
         }
 
-        mod Extend {
-            use std::borrow::ToOwned;
+        #[cfg(feature = "sqlx-postgres")]
+        mod sqlx {
+            use crate::*;
+            use sqlx::{postgres::PgArgumentBuffer, Encode, Postgres, Type};
 
             #[test]
-            fn by_value_ref() {
-                let mut a = vec1![0];
-                a.extend(vec1![33u8].iter());
-                assert_eq!(a, &[0, 33]);
+            fn same_type_info_as_vec() {
+                assert_eq!(
+                    <Vec1<i32> as Type<Postgres>>::type_info(),
+                    <Vec<i32> as Type<Postgres>>::type_info()
+                );
             }
 
             #[test]
-            fn by_value() {
-                let mut a = vec1!["hy".to_owned()];
-                a.extend(vec1!["ho".to_owned()].into_iter());
-                assert_eq!(a, &["hy".to_owned(), "ho".to_owned()]);
+            fn encodes_like_vec() {
+                let mut vec1_buf = PgArgumentBuffer::default();
+                let _ = Encode::<Postgres>::encode_by_ref(&vec1![1i32, 2], &mut vec1_buf);
+                let mut vec_buf = PgArgumentBuffer::default();
+                let _ = Encode::<Postgres>::encode_by_ref(&std::vec![1i32, 2], &mut vec_buf);
+                assert_eq!(&**vec1_buf, &**vec_buf);
             }
         }
 
-        mod TryFrom {
+        #[cfg(feature = "ufmt")]
+        mod ufmt {
             use crate::*;
-            use std::{borrow::ToOwned, convert::TryFrom};
 
-            #[test]
-            fn from_slice_ref() {
-                let slice: &[String] = &["hy".to_owned()];
-                let vec = Vec1::try_from(slice).unwrap();
-                assert_eq!(vec, slice);
+            struct Buf(String);
 
-                let slice: &[String] = &[];
-                Vec1::try_from(slice).unwrap_err();
+            impl ufmt::uWrite for Buf {
+                type Error = core::convert::Infallible;
+
+                fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+                    self.0.push_str(s);
+                    Ok(())
+                }
             }
 
             #[test]
-            fn from_slice_mut() {
-                let slice: &mut [String] = &mut ["hy".to_owned()];
-                let vec = Vec1::try_from(&mut *slice).unwrap();
-                assert_eq!(vec, slice);
-
-                let slice: &mut [String] = &mut [];
-                Vec1::try_from(slice).unwrap_err();
+            fn debug_formats_like_a_slice() {
+                let mut buf = Buf(String::new());
+                ufmt::uwrite!(buf, "{:?}", vec1![1u8, 2, 3]).unwrap();
+                assert_eq!(buf.0, "[1, 2, 3]");
             }
+        }
 
+        #[cfg(feature = "defmt")]
+        mod defmt {
+            use crate::*;
+
+            // Actually logging requires a global defmt logger (and a decoder), so
+            // this only checks that the impl exists.
             #[test]
-            fn from_str() {
-                let vec = Vec1::<u8>::try_from("hy").unwrap();
-                assert_eq!(vec, "hy".as_bytes());
-                Vec1::<u8>::try_from("").unwrap_err();
+            fn implements_format() {
+                fn assert_format<T: defmt::Format>() {}
+                assert_format::<Vec1<u8>>();
+                assert_format::<Vec1<&str>>();
+                #[cfg(feature = "smallvec-v1")]
+                assert_format::<crate::smallvec_v1::SmallVec1<[u8; 4]>>();
             }
+        }
 
-            #[test]
-            fn from_array() {
-                // we just test if there is a impl for a arbitrary len
-                // which here is good enough but far from complete coverage!
-                let array = [11; 100];
-                let vec = Vec1::try_from(array).unwrap();
-                assert_eq!(vec.iter().sum::<i32>(), 1100);
+        #[cfg(feature = "uniffi")]
+        mod uniffi {
+            use crate::*;
+            use uniffi_core::{Lift, Lower};
 
-                Vec1::try_from([0u8; 0]).unwrap_err();
-            }
+            struct Tag;
 
             #[test]
-            fn from_array_ref() {
-                // we just test if there is a impl for a arbitrary len
-                // which here is good enough but far from complete coverage!
-                let array = [11; 100];
-                let vec = Vec1::try_from(&array).unwrap();
-                assert_eq!(vec.iter().sum::<i32>(), 1100);
-
-                Vec1::try_from([0u8; 0]).unwrap_err();
+            fn lower_and_lift() {
+                let vec = vec1![1u32, 2, 3];
+                let buf = <Vec1<u32> as Lower<Tag>>::lower(vec.clone());
+                let got = <Vec1<u32> as Lift<Tag>>::try_lift(buf).unwrap();
+                assert_eq!(got, vec);
             }
 
             #[test]
-            fn from_array_mut() {
-                // we just test if there is a impl for a arbitrary len
-                // which here is good enough but far from complete coverage!
-                let mut array = [11; 100];
-                let vec = Vec1::try_from(&mut array).unwrap();
-                assert_eq!(vec.iter().sum::<i32>(), 1100);
-
-                Vec1::try_from([0u8; 0]).unwrap_err();
+            fn lift_rejects_empty() {
+                let buf = <Vec<u32> as Lower<Tag>>::lower(Vec::new());
+                <Vec1<u32> as Lift<Tag>>::try_lift(buf).unwrap_err();
             }
+        }
 
-            #[test]
-            fn from_binary_heap() {
-                use std::collections::BinaryHeap;
-                let mut heap = BinaryHeap::new();
-                heap.push(1u8);
-                heap.push(100);
-                heap.push(3);
+        #[cfg(feature = "im")]
+        mod im {
+            use crate::*;
 
-                let vec = Vec1::try_from(heap).unwrap();
-                assert_eq!(vec.len(), 3);
-                assert_eq!(vec.first(), &100);
-                assert!(vec.contains(&3));
-                assert!(vec.contains(&1));
+            #[test]
+            fn roundtrip() {
+                let vector = im::Vector::from(vec1![1u8, 2, 3]);
+                assert_eq!(vector, im::vector![1u8, 2, 3]);
+                assert_eq!(Vec1::try_from(vector), Ok(vec1![1u8, 2, 3]));
+            }
 
-                Vec1::<u8>::try_from(BinaryHeap::new()).unwrap_err();
+            #[test]
+            fn empty_vector_is_rejected() {
+                assert_eq!(Vec1::try_from(im::Vector::<u8>::new()), Err(Size0Error));
             }
+        }
+
+        #[cfg(feature = "indexmap")]
+        mod indexmap {
+            use crate::*;
+            use indexmap::{IndexMap, IndexSet};
 
             #[test]
-            fn from_boxed_slice() {
-                let boxed = Box::new([20u8; 10]) as Box<[u8]>;
-                let vec = Vec1::try_from(boxed).unwrap();
-                assert_eq!(vec, &[20u8; 10]);
+            fn from_index_set_keeps_order() {
+                let set: IndexSet<u8> = [3, 1, 2].into_iter().collect();
+                assert_eq!(Vec1::try_from(set), Ok(vec1![3, 1, 2]));
+                assert_eq!(Vec1::<u8>::try_from(IndexSet::new()), Err(Size0Error));
             }
 
-            #[cfg(feature = "std")]
             #[test]
-            fn from_cstring() {
-                let cstring = CString::new("ABA").unwrap();
-                let vec = Vec1::<u8>::try_from(cstring).unwrap();
-                assert_eq!(vec, &[65, 66, 65]);
+            fn from_index_map_keeps_order() {
+                let map: IndexMap<u8, &str> = [(2, "b"), (1, "a")].into_iter().collect();
+                assert_eq!(Vec1::try_from(map), Ok(vec1![(2, "b"), (1, "a")]));
+                assert_eq!(Vec1::<(u8, u8)>::try_from(IndexMap::new()), Err(Size0Error));
+            }
 
-                let cstring = CString::new("").unwrap();
-                Vec1::<u8>::try_from(cstring).unwrap_err();
+            #[test]
+            fn into_index_set_and_map() {
+                let set: IndexSet<u8> = vec1![2, 1, 2].into();
+                assert_eq!(set.into_iter().collect::<Vec<_>>(), [2, 1]);
+                let map: IndexMap<u8, u8> = vec1![(2, 1), (1, 1), (2, 3)].into();
+                assert_eq!(map.into_iter().collect::<Vec<_>>(), [(2, 3), (1, 1)]);
             }
+        }
+
+        #[cfg(feature = "bytes")]
+        mod bytes {
+            use crate::*;
+            use bytes::{Bytes, BytesMut};
 
-            #[cfg(feature = "std")]
             #[test]
-            fn from_cow() {
-                let slice: &[u8] = &[12u8, 33];
-                let cow = Cow::Borrowed(slice);
-                let vec = Vec1::try_from(cow).unwrap();
-                assert_eq!(vec, slice);
+            fn into_bytes() {
+                assert_eq!(Bytes::from(vec1![1u8, 2]), &[1u8, 2][..]);
+                assert_eq!(BytesMut::from(vec1![1u8, 2]), &[1u8, 2][..]);
+            }
 
-                let slice: &[u8] = &[];
-                let cow = Cow::Borrowed(slice);
-                Vec1::try_from(cow).unwrap_err();
+            #[test]
+            fn try_from_bytes() {
+                assert_eq!(
+                    Vec1::try_from(Bytes::from_static(b"ab")),
+                    Ok(vec1![b'a', b'b'])
+                );
+                assert_eq!(Vec1::try_from(BytesMut::from("ab")), Ok(vec1![b'a', b'b']));
+                assert_eq!(Vec1::try_from(Bytes::new()), Err(Size0Error));
+                assert_eq!(Vec1::try_from(BytesMut::new()), Err(Size0Error));
             }
+        }
+
+        #[cfg(feature = "ndarray")]
+        mod ndarray {
+            use crate::*;
+            use ndarray::{s, Array1};
 
             #[test]
-            fn from_string() {
-                let vec = Vec1::<u8>::try_from("ABA".to_owned()).unwrap();
-                assert_eq!(vec, &[65, 66, 65]);
+            fn roundtrip() {
+                let array = Array1::from(vec1![1.0f64, 2.0, 3.0]);
+                assert_eq!(array, ndarray::arr1(&[1.0, 2.0, 3.0]));
+                assert_eq!(Vec1::try_from(array), Ok(vec1![1.0, 2.0, 3.0]));
+            }
 
-                Vec1::<u8>::try_from("".to_owned()).unwrap_err();
+            #[test]
+            fn non_standard_layout() {
+                let array = ndarray::arr1(&[1u8, 2, 3]).slice_move(s![..;-1]);
+                assert_eq!(Vec1::try_from(array), Ok(vec1![3, 2, 1]));
             }
 
             #[test]
-            fn from_vec_deque() {
-                let queue = VecDeque::from(std::vec![1u8, 2, 3]);
-                let vec = Vec1::try_from(queue).unwrap();
-                assert_eq!(vec, &[1u8, 2, 3]);
+            fn empty_array_is_rejected() {
+                assert_eq!(Vec1::try_from(Array1::<u8>::zeros(0)), Err(Size0Error));
+            }
+        }
 
-                Vec1::<u8>::try_from(VecDeque::new()).unwrap_err();
+        #[cfg(feature = "triomphe")]
+        mod triomphe {
+            use crate::*;
+            use triomphe::{Arc, ThinArc};
+
+            #[test]
+            fn into_arc() {
+                let arc: Arc<[u8]> = Arc::from(vec1![1u8, 2]);
+                assert_eq!(&*arc, &[1u8, 2]);
+                let thin: ThinArc<(), u8> = ThinArc::from(vec1![1u8, 2]);
+                assert_eq!(&thin.slice, &[1u8, 2]);
             }
         }
 
-        mod Hash {
+        #[cfg(feature = "ecow")]
+        mod ecow {
             use crate::*;
-            use std::{
-                collections::hash_map::DefaultHasher,
-                hash::{Hash, Hasher},
-            };
+            use ecow::{eco_vec, EcoVec};
 
             #[test]
-            fn hash() {
-                let a = vec1![1u8, 10, 33, 12];
-                let mut hasher = DefaultHasher::new();
-                a.hash(&mut hasher);
-                let a_state = hasher.finish();
+            fn conversions() {
+                let eco = EcoVec::from(vec1![1u8, 2]);
+                assert_eq!(eco, [1u8, 2]);
+                assert_eq!(Vec1::try_from(eco.clone()), Ok(vec1![1u8, 2]));
+                assert_eq!(Vec1::try_from(eco), Ok(vec1![1u8, 2]));
+                assert_eq!(Vec1::try_from(eco_vec![0u8; 0]), Err(Size0Error));
+            }
+        }
 
-                let b = a.into_vec();
-                let mut hasher = DefaultHasher::new();
-                b.hash(&mut hasher);
-                let b_state = hasher.finish();
+        #[cfg(feature = "rand")]
+        mod rand {
+            use crate::*;
+            use core::num::NonZeroUsize;
+            use rand::{rngs::SmallRng, SeedableRng};
 
-                assert_eq!(a_state, b_state);
+            #[test]
+            fn choose1() {
+                let mut rng = SmallRng::seed_from_u64(42);
+                let mut a = vec1![1u8, 2, 3];
+                assert!(a.contains(a.choose1(&mut rng)));
+                *a.choose1_mut(&mut rng) = 10;
+                assert!(a.contains(&10));
+                assert_eq!(vec1![4u8].choose1(&mut rng), &4);
             }
 
             #[test]
-            fn hash_slice() {
-                let a: &[_] = &[vec1![1u8, 10, 33, 12], vec1![22, 12]];
-                let mut hasher = DefaultHasher::new();
-                <Vec1<u8> as Hash>::hash_slice(a, &mut hasher);
-                let a_state = hasher.finish();
+            fn choose_weighted() {
+                let mut rng = SmallRng::seed_from_u64(42);
+                let a = vec1![("never", 0u32), ("always", 3)];
+                for _ in 0..10 {
+                    assert_eq!(a.choose_weighted(&mut rng, |v| v.1).unwrap().0, "always");
+                }
+                assert!(vec1![0u32].choose_weighted(&mut rng, |v| *v).is_err());
+            }
 
-                let b: &[_] = &[std::vec![1u8, 10, 33, 12], std::vec![22, 12]];
-                let mut hasher = DefaultHasher::new();
-                <Vec<u8> as Hash>::hash_slice(b, &mut hasher);
-                let b_state = hasher.finish();
+            #[test]
+            fn sample() {
+                let mut rng = SmallRng::seed_from_u64(42);
+                let a = vec1![1u8, 2, 3, 4];
+                let mut sampled = a.sample(&mut rng, NonZeroUsize::new(2).unwrap());
+                assert_eq!(sampled.len(), 2);
+                sampled.sort_and_dedup();
+                assert_eq!(sampled.len(), 2);
+                let mut all = a.sample(&mut rng, NonZeroUsize::new(10).unwrap());
+                all.sort();
+                assert_eq!(all, vec1![&1, &2, &3, &4]);
+            }
 
-                assert_eq!(a_state, b_state);
+            #[test]
+            fn shuffle() {
+                let mut rng = SmallRng::seed_from_u64(42);
+                let mut a = vec1![1u8, 2, 3, 4, 5];
+                a.shuffle(&mut rng);
+                a.sort();
+                assert_eq!(a, vec1![1u8, 2, 3, 4, 5]);
+            }
+
+            #[test]
+            fn into_shuffled() {
+                let mut rng = SmallRng::seed_from_u64(42);
+                let mut a = vec1![1u8, 2, 3, 4, 5].into_shuffled(&mut rng);
+                a.sort();
+                assert_eq!(a, vec1![1u8, 2, 3, 4, 5]);
             }
         }
 
-        mod Index {
-            use std::ops::Index;
+        #[cfg(feature = "tokio")]
+        mod tokio {
+            use crate::*;
+            use tokio::io::AsyncWriteExt;
 
             #[test]
-            fn index() {
-                let vec = vec1![34u8, 99, 10, 73];
-                assert_eq!(vec.index(1..3), &[99, 10]);
-                assert_eq!(&vec[1..3], &[99, 10]);
-                assert_eq!(vec[0], 34u8);
+            fn async_write() {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .build()
+                    .unwrap();
+                let mut a = vec1![b'a'];
+                runtime.block_on(async {
+                    a.write_all(b"bc").await.unwrap();
+                    a.flush().await.unwrap();
+                    a.shutdown().await.unwrap();
+                });
+                assert_eq!(a, b"abc");
             }
         }
 
-        mod IndexMut {
-            use std::ops::IndexMut;
+        #[cfg(feature = "miniserde")]
+        mod miniserde {
+            use crate::*;
+            use miniserde::json;
 
             #[test]
-            fn index_mut() {
-                let mut vec = vec1![34u8, 99, 10, 73];
-                assert_eq!(vec.index_mut(1..3), &mut [99, 10]);
-                assert_eq!(&mut vec[1..3], &mut [99, 10]);
+            fn roundtrip() {
+                let a = vec1![1u8, 2, 3];
+                let json = json::to_string(&a);
+                assert_eq!(json, "[1,2,3]");
+                assert_eq!(json::from_str::<Vec1<u8>>(&json).unwrap(), a);
             }
-        }
 
-        mod IntoIterator {
             #[test]
-            fn of_self() {
-                let vec = vec1![1u8, 33u8, 57];
-                let mut iter = vec.into_iter();
-                assert_eq!(iter.size_hint(), (3, Some(3)));
-                // impl. ExactSizedIterator
-                assert_eq!(iter.len(), 3);
-                assert_eq!(iter.next(), Some(1));
-                // impl. DoubleEndedIterator
-                assert_eq!(iter.next_back(), Some(57));
-                assert_eq!(iter.next(), Some(33));
-                assert_eq!(iter.next(), None);
+            fn empty_is_rejected() {
+                assert!(json::from_str::<Vec1<u8>>("[]").is_err());
+                assert!(json::from_str::<Vec1<u8>>("[\"a\"]").is_err());
             }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        mod arbitrary {
+            use crate::*;
+            use arbitrary::{Arbitrary, Unstructured};
 
             #[test]
-            fn of_self_ref() {
-                let vec = vec1![1u8, 33u8, 57];
-                let mut iter = (&vec).into_iter();
-                assert_eq!(iter.size_hint(), (3, Some(3)));
-                // impl. ExactSizedIterator
-                assert_eq!(iter.len(), 3);
-                assert_eq!(iter.next(), Some(&1));
-                // impl. DoubleEndedIterator
-                assert_eq!(iter.next_back(), Some(&57));
-                assert_eq!(iter.next(), Some(&33));
-                assert_eq!(iter.next(), None);
+            fn never_empty() {
+                let mut u = Unstructured::new(&[]);
+                assert_eq!(Vec1::<u8>::arbitrary(&mut u).unwrap(), vec1![0u8]);
+                let u = Unstructured::new(&[1, 2, 3]);
+                assert_eq!(Vec1::<u8>::arbitrary_take_rest(u).unwrap().first(), &1);
+                assert_eq!(Vec1::<u32>::size_hint(0), (4, None));
             }
+        }
+
+        #[cfg(feature = "rayon")]
+        mod rayon {
+            use crate::*;
+            use rayon::iter::{
+                IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+            };
 
             #[test]
-            fn of_self_mut() {
-                let mut vec = vec1![1u8, 33u8, 57];
-                let mut iter = (&mut vec).into_iter();
-                assert_eq!(iter.size_hint(), (3, Some(3)));
-                // impl. ExactSizedIterator
-                assert_eq!(iter.len(), 3);
-                assert_eq!(iter.next(), Some(&mut 1));
-                // impl. DoubleEndedIterator
-                assert_eq!(iter.next_back(), Some(&mut 57));
-                assert_eq!(iter.next(), Some(&mut 33));
-                assert_eq!(iter.next(), None);
+            fn par_iter() {
+                let mut a = vec1![1u32, 2, 3];
+                a.par_iter_mut().for_each(|v| *v *= 2);
+                assert_eq!(a.par_iter().sum::<u32>(), 12);
+                assert_eq!(a.par_mapped(|v| v + 1), vec1![3u32, 5, 7]);
             }
         }
 
-        mod Ord {
-            use std::cmp::Ordering;
+        #[cfg(feature = "speedy")]
+        mod speedy {
+            use crate::*;
+            use speedy::{Readable, Writable};
 
             #[test]
-            fn cmp() {
-                // just make sure we implemented it
-                // we will forward to Vec's impl. anyway
-                // so no reasone to test if cmp works correctly
-                // (it doing so is desired sue proptest!).
-                let a = vec1![1u8, 3, 4];
-                let b = vec1![1u8, 4, 2];
-                assert_eq!(a.cmp(&b), Ordering::Less);
+            fn roundtrip() {
+                let a = vec1![1u16, 2, 3];
+                let bytes = a.write_to_vec().unwrap();
+                assert_eq!(Vec1::<u16>::read_from_buffer(&bytes).unwrap(), a);
+            }
+
+            #[test]
+            fn empty_is_rejected() {
+                let bytes = Vec::<u16>::new().write_to_vec().unwrap();
+                assert!(Vec1::<u16>::read_from_buffer(&bytes).is_err());
             }
         }
 
-        mod PartialEq {
+        #[cfg(feature = "juniper")]
+        mod juniper {
             use crate::*;
-            use std::borrow::ToOwned;
+            use juniper::{
+                execute_sync, graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables,
+            };
+            // the graphql_object macro expects the std prelude
+            use std::format;
+
+            struct Query;
+
+            #[graphql_object]
+            impl Query {
+                fn values() -> Vec1<i32> {
+                    vec1![1, 2]
+                }
+
+                fn first(values: Vec1<i32>) -> i32 {
+                    *values.first()
+                }
+            }
+
+            fn run(query: &str) -> Result<juniper::Value, ()> {
+                let schema = RootNode::new(
+                    Query,
+                    EmptyMutation::<()>::new(),
+                    EmptySubscription::<()>::new(),
+                );
+                match execute_sync(query, None, &schema, &Variables::new(), &()) {
+                    Ok((value, errors)) if errors.is_empty() => Ok(value),
+                    _ => Err(()),
+                }
+            }
 
             #[test]
-            fn to_array_ref() {
-                let vec = vec1![67u8, 73, 12];
-                let array: &[u8; 3] = &[67, 73, 12];
-                let array2: &[u8; 3] = &[67, 73, 33];
-                assert_eq!(vec.eq(&array), true);
-                assert_eq!(vec.eq(&array2), false);
+            fn output_and_input() {
+                let value = run("{ values first(values: [3, 4]) }").unwrap();
+                let object = value.as_object_value().unwrap();
+                let values = object.get_field_value("values").unwrap();
+                let values = values.as_list_value().unwrap();
+                let values = values
+                    .iter()
+                    .map(|v| *v.as_scalar_value::<i32>().unwrap())
+                    .collect::<Vec<_>>();
+                assert_eq!(values, [1, 2]);
+                let first = object.get_field_value("first").unwrap();
+                assert_eq!(first.as_scalar_value::<i32>(), Some(&3));
             }
 
             #[test]
-            fn to_slice_ref() {
-                let vec = vec1![67u8, 73, 12];
-                let array: &[u8] = &[67, 73, 12];
-                let array2: &[u8] = &[67, 73, 33];
-                assert_eq!(vec.eq(&array), true);
-                assert_eq!(vec.eq(&array2), false);
+            fn empty_input_is_rejected() {
+                assert_eq!(run("{ first(values: []) }"), Err(()));
+            }
+        }
+
+        #[cfg(feature = "rocket")]
+        mod rocket {
+            use crate::*;
+            use rocket::form::{error::ErrorKind, Form};
+
+            #[derive(Debug, rocket::FromForm)]
+            struct Query {
+                ids: Vec1<u32>,
             }
 
             #[test]
-            fn to_slice_mut() {
-                let vec = vec1![67u8, 73, 12];
-                let array: &mut [u8] = &mut [67, 73, 12];
-                let array2: &mut [u8] = &mut [67, 73, 33];
-                assert_eq!(vec.eq(&array), true);
-                assert_eq!(vec.eq(&array2), false);
+            fn parses_values() {
+                let query = Form::<Query>::parse("ids=1&ids=3").unwrap();
+                assert_eq!(query.ids, vec1![1, 3]);
             }
 
             #[test]
-            fn to_array() {
-                let vec = vec1![67u8, 73, 12];
-                let array: [u8; 3] = [67, 73, 12];
-                let array2: [u8; 3] = [67, 73, 33];
-                assert_eq!(vec.eq(&array), true);
-                assert_eq!(vec.eq(&array2), false);
+            fn missing_field_is_rejected() {
+                let errors = Form::<Query>::parse("other=1").unwrap_err();
+                assert!(errors
+                    .iter()
+                    .any(|e| e.name.as_ref().map_or(false, |n| n == "ids")));
+                assert!(errors.iter().any(|e| matches!(e.kind, ErrorKind::Missing)));
             }
+        }
+
+        #[cfg(feature = "musli")]
+        mod musli {
+            use crate::*;
 
             #[test]
-            fn to_slice() {
-                let vec = vec1![67u8, 73, 12];
-                let array: &[u8] = &[67, 73, 12];
-                let array2: &[u8] = &[67, 73, 33];
+            fn roundtrip() {
+                let vec = vec1![1u32, 2, 3];
+                let bytes = musli_storage::to_vec(&vec).unwrap();
+                let got: Vec1<u32> = musli_storage::from_slice(&bytes).unwrap();
+                assert_eq!(got, vec);
+            }
 
-                assert_eq!(<Vec1<u8> as PartialEq<[u8]>>::eq(&vec, array), true);
-                assert_eq!(<Vec1<u8> as PartialEq<[u8]>>::eq(&vec, array2), false);
+            #[test]
+            fn rejects_empty() {
+                let bytes = musli_storage::to_vec(&Vec::<u32>::new()).unwrap();
+                musli_storage::from_slice::<Vec1<u32>>(&bytes).unwrap_err();
             }
+        }
+
+        #[cfg(feature = "validator")]
+        mod validator {
+            use validator::ValidateLength;
 
             #[test]
-            fn to_self_kind() {
-                let a = vec1!["hy".to_owned()];
-                let b = vec1!["hy"];
-                assert_eq!(a, b);
+            fn validate_length() {
+                let vec = vec1![1u8, 2, 3];
+                assert_eq!(vec.length(), Some(3));
+                assert!(vec.validate_length(Some(1), Some(3), None));
+                assert!(!vec.validate_length(Some(4), None, None));
             }
         }
 
-        mod PartialOrd {
-            use std::cmp::Ordering;
+        #[cfg(feature = "garde")]
+        mod garde {
+            use garde::rules::length::simple::Simple;
 
             #[test]
-            fn with_self_kind() {
-                let a = vec1!["b"];
-                let b = vec1!["a"];
-                assert_eq!(a.partial_cmp(&b), Some(Ordering::Greater));
+            fn validate_length() {
+                let vec = vec1![1u8, 2, 3];
+                vec.validate_length(1, 3).unwrap();
+                vec.validate_length(4, 10).unwrap_err();
             }
         }
 
-        #[cfg(feature = "std")]
-        mod Write {
-            use std::io::Write;
+        #[cfg(feature = "zeroize")]
+        mod zeroize {
+            use crate::*;
+            use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
             #[test]
-            fn for_bytes() {
-                let mut v = vec1![1u8];
-                v.write(&[65, 100, 12]).unwrap();
-                assert_eq!(v, &[1u8, 65, 100, 12]);
+            fn zeroize() {
+                let mut a = vec1![1u8, 2, 3];
+                a.reserve(8);
+                a.zeroize();
+                assert_eq!(a, [0]);
+                let mut a = vec1![String::from("secret")];
+                a.zeroize();
+                assert_eq!(a, [""]);
+            }
+
+            #[test]
+            fn zeroize_on_drop() {
+                fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+                assert_zeroize_on_drop(&vec1![Zeroizing::new(1u8)]);
             }
         }
 
@@ -1963,6 +4528,61 @@ mod test {
                 let json = serde_json::to_string(&vec).unwrap();
                 assert_eq!(json, "[1,2,3]");
             }
+
+            #[test]
+            fn bogus_size_hint() {
+                use ::serde::de::{
+                    value::{Error, SeqAccessDeserializer},
+                    Deserialize, DeserializeSeed, IntoDeserializer, SeqAccess,
+                };
+
+                struct BogusHint(Option<u8>);
+
+                impl<'de> SeqAccess<'de> for BogusHint {
+                    type Error = Error;
+
+                    fn next_element_seed<T: DeserializeSeed<'de>>(
+                        &mut self,
+                        seed: T,
+                    ) -> Result<Option<T::Value>, Error> {
+                        self.0
+                            .take()
+                            .map(|v| seed.deserialize(v.into_deserializer()))
+                            .transpose()
+                    }
+
+                    fn size_hint(&self) -> Option<usize> {
+                        Some(usize::MAX)
+                    }
+                }
+
+                let vec = Vec1::<u8>::deserialize(SeqAccessDeserializer::new(BogusHint(Some(1))))
+                    .unwrap();
+                assert_eq!(vec, vec1![1]);
+                assert!(vec.capacity() <= 1024 * 1024);
+            }
+        }
+
+        #[cfg(feature = "serde_with")]
+        mod serde_with {
+            use crate::*;
+            use serde_with::{As, DisplayFromStr};
+
+            #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+            struct Numbers(#[serde(with = "As::<Vec1<DisplayFromStr>>")] Vec1<u8>);
+
+            #[test]
+            fn serialize_as() {
+                let json = serde_json::to_string(&Numbers(vec1![1, 2])).unwrap();
+                assert_eq!(json, r#"["1","2"]"#);
+                let numbers: Numbers = serde_json::from_str(&json).unwrap();
+                assert_eq!(numbers, Numbers(vec1![1, 2]));
+            }
+
+            #[test]
+            fn empty_is_rejected() {
+                assert!(serde_json::from_str::<Numbers>("[]").is_err());
+            }
         }
     }
 
@@ -0,0 +1,139 @@
+//! A trait abstracting over the non-empty vector types (`NonEmptyCollection`).
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::{vec1, NonEmptyCollection, Vec1};
+//!
+//! fn push_double_of_last<C>(values: &mut C)
+//! where
+//!     C: NonEmptyCollection<Item = u32>,
+//! {
+//!     let last = *values.last();
+//!     values.push(last * 2);
+//! }
+//!
+//! let mut values: Vec1<u32> = vec1![1, 2];
+//! push_double_of_last(&mut values);
+//! assert_eq!(values, [1, 2, 4]);
+//! ```
+
+use core::num::NonZeroUsize;
+
+use alloc::vec::Vec;
+
+use crate::{Size0Error, Slice1, Vec1};
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// Common API of the non-empty vector types (`Vec1` and `SmallVec1`).
+///
+/// This allows writing code which is generic over the backing storage.
+///
+/// The trait is sealed, i.e. it can not be implemented outside of this crate.
+/// The types themselves have inherent methods of the same names, so the trait
+/// only needs to be imported when writing generic code.
+pub trait NonEmptyCollection: sealed::Sealed + Sized {
+    /// The type of the elements.
+    type Item;
+
+    /// Creates a new instance containing just `first`.
+    fn new(first: Self::Item) -> Self;
+
+    /// Tries to create an instance from a `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is empty.
+    fn try_from_vec(vec: Vec<Self::Item>) -> Result<Self, Size0Error>;
+
+    /// Turns this into a `Vec`.
+    fn into_vec(self) -> Vec<Self::Item>;
+
+    /// Turns this into a `Vec1`.
+    fn into_vec1(self) -> Vec1<Self::Item> {
+        //UNWRAP_SAFE: len is at least 1
+        Vec1::try_from_vec(self.into_vec()).unwrap()
+    }
+
+    /// Returns the elements as a non-empty `Slice1`.
+    fn as_slice1(&self) -> &Slice1<Self::Item>;
+
+    /// Returns the elements as a mutable non-empty `Slice1`.
+    fn as_mut_slice1(&mut self) -> &mut Slice1<Self::Item>;
+
+    /// Returns the length as `NonZeroUsize`.
+    fn len_nonzero(&self) -> NonZeroUsize {
+        self.as_slice1().len_nonzero()
+    }
+
+    /// Returns a reference to the first element.
+    fn first(&self) -> &Self::Item {
+        self.as_slice1().first()
+    }
+
+    /// Returns a mutable reference to the first element.
+    fn first_mut(&mut self) -> &mut Self::Item {
+        self.as_mut_slice1().first_mut()
+    }
+
+    /// Returns a reference to the last element.
+    fn last(&self) -> &Self::Item {
+        self.as_slice1().last()
+    }
+
+    /// Returns a mutable reference to the last element.
+    fn last_mut(&mut self) -> &mut Self::Item {
+        self.as_mut_slice1().last_mut()
+    }
+
+    /// Appends an element to the back.
+    fn push(&mut self, value: Self::Item);
+
+    /// Removes the last element, if there is more than one element.
+    ///
+    /// Like the inherent `pop` methods this isn't `try_` prefixed even
+    /// though it can fail.
+    ///
+    /// # Errors
+    ///
+    /// If len is 1 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    fn pop(&mut self) -> Result<Self::Item, Size0Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    fn exercise<C>(mut values: C) -> Vec1<u8>
+    where
+        C: NonEmptyCollection<Item = u8>,
+    {
+        *values.first_mut() += 10;
+        values.push(*values.last() + 1);
+        assert_eq!(values.len_nonzero().get(), 3);
+        assert_eq!(values.pop(), Ok(3));
+        assert_eq!(values.pop(), Ok(2));
+        assert_eq!(values.pop(), Err(Size0Error));
+        assert_eq!(C::try_from_vec(Vec::new()).err(), Some(Size0Error));
+        assert_eq!(C::new(1).into_vec(), [1]);
+        values.into_vec1()
+    }
+
+    #[test]
+    fn vec1() {
+        assert_eq!(exercise(vec1![1u8, 2]), vec1![11u8]);
+    }
+
+    #[cfg(feature = "smallvec-v1")]
+    #[test]
+    fn smallvec1() {
+        use crate::smallvec_v1::SmallVec1;
+        let values = SmallVec1::<[u8; 2]>::try_from_vec(vec1![1u8, 2].into_vec()).unwrap();
+        assert_eq!(exercise(values), vec1![11u8]);
+    }
+}
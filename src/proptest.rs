@@ -0,0 +1,147 @@
+//! Proptest strategies for `Vec1` (and `SmallVec1`).
+//!
+//! Besides the strategy functions in this module `Vec1` (and `SmallVec1`)
+//! implement `proptest::arbitrary::Arbitrary`, so `any::<Vec1<T>>()` works.
+//!
+//! As a `Vec1` can't be empty a size range including 0 is treated as if it
+//! started at 1, e.g. `0..10` produces vectors with 1 to 9 elements.
+//!
+//! # Example
+//!
+//! ```
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     # /*
+//!     #[test]
+//!     # */
+//!     fn first_is_min_after_sort(mut values in vec1::proptest::vec1(any::<u8>(), 1..20)) {
+//!         values.sort();
+//!         prop_assert_eq!(values.first(), values.iter().min().unwrap());
+//!     }
+//! }
+//! # first_is_min_after_sort();
+//! ```
+
+use ::proptest::{
+    arbitrary::{any_with, Arbitrary},
+    collection::{self, SizeRange, VecStrategy},
+    strategy::{Map, Strategy},
+};
+
+use alloc::vec::Vec;
+
+use crate::Vec1;
+
+/// Strategy returned by [`vec1()`].
+pub type Vec1Strategy<S> =
+    Map<VecStrategy<S>, fn(Vec<<S as Strategy>::Value>) -> Vec1<<S as Strategy>::Value>>;
+
+/// Creates a strategy producing a `Vec1` with elements from `element` and a length in `size`.
+///
+/// A minimum size of 0 is treated as 1.
+pub fn vec1<S>(element: S, size: impl Into<SizeRange>) -> Vec1Strategy<S>
+where
+    S: Strategy,
+{
+    collection::vec(element, non_empty(size.into())).prop_map(|vec| {
+        //UNWRAP_SAFE: size range starts at 1 or above
+        Vec1::try_from_vec(vec).unwrap()
+    })
+}
+
+impl<T> Arbitrary for Vec1<T>
+where
+    T: Arbitrary,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = Vec1Strategy<T::Strategy>;
+
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        vec1(any_with::<T>(params), size)
+    }
+}
+
+#[cfg(feature = "smallvec-v1")]
+pub use self::smallvec_v1::*;
+
+#[cfg(feature = "smallvec-v1")]
+mod smallvec_v1 {
+    use super::*;
+    use crate::smallvec_v1::SmallVec1;
+    use core::fmt::Debug;
+    use smallvec_v1_::Array;
+
+    /// Strategy returned by [`smallvec1()`].
+    pub type SmallVec1Strategy<A, S> =
+        Map<VecStrategy<S>, fn(Vec<<A as Array>::Item>) -> SmallVec1<A>>;
+
+    /// Creates a strategy producing a `SmallVec1` with elements from `element` and a length in `size`.
+    ///
+    /// A minimum size of 0 is treated as 1.
+    pub fn smallvec1<A, S>(element: S, size: impl Into<SizeRange>) -> SmallVec1Strategy<A, S>
+    where
+        A: Array,
+        A::Item: Debug,
+        S: Strategy<Value = A::Item>,
+    {
+        collection::vec(element, non_empty(size.into())).prop_map(|vec| {
+            //UNWRAP_SAFE: size range starts at 1 or above
+            SmallVec1::try_from_vec(vec).unwrap()
+        })
+    }
+
+    impl<A> Arbitrary for SmallVec1<A>
+    where
+        A: Array,
+        A::Item: Arbitrary,
+    {
+        type Parameters = (SizeRange, <A::Item as Arbitrary>::Parameters);
+        type Strategy = SmallVec1Strategy<A, <A::Item as Arbitrary>::Strategy>;
+
+        fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+            smallvec1(any_with::<A::Item>(params), size)
+        }
+    }
+}
+
+fn non_empty(size: SizeRange) -> SizeRange {
+    let (start, end_incl) = size.start_end_incl();
+    SizeRange::new(start.max(1)..=end_incl.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn vec1_respects_size(vec in vec1(any::<u8>(), 2..5)) {
+            prop_assert!((2..5).contains(&vec.len()));
+        }
+
+        #[test]
+        fn zero_is_treated_as_one(vec in vec1(any::<u8>(), 0..=1)) {
+            prop_assert_eq!(vec.len(), 1);
+        }
+
+        #[test]
+        fn arbitrary_is_never_empty(vec in any::<Vec1<u8>>()) {
+            prop_assert!(!vec.is_empty());
+        }
+    }
+
+    #[cfg(feature = "smallvec-v1")]
+    proptest! {
+        #[test]
+        fn smallvec1_respects_size(vec in smallvec1::<[u8; 4], _>(any::<u8>(), 1..8)) {
+            prop_assert!((1..8).contains(&vec.len()));
+        }
+
+        #[test]
+        fn smallvec1_arbitrary_is_never_empty(vec in any::<crate::smallvec_v1::SmallVec1<[u8; 2]>>()) {
+            prop_assert!(!vec.is_empty());
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Helpers for alternative serde representations of `Vec1`.
+//!
+//! The modules in here are meant to be used with `#[serde(with = "...")]`.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use vec1::Vec1;
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Filter {
+//!     #[serde(default, with = "vec1::serde::empty_as_none")]
+//!     tags: Option<Vec1<String>>,
+//! }
+//!
+//! let filter: Filter = serde_json::from_str(r#"{"tags": []}"#).unwrap();
+//! assert_eq!(filter, Filter { tags: None });
+//! assert_eq!(serde_json::to_string(&filter).unwrap(), r#"{"tags":[]}"#);
+//! ```
+
+/// (De-)serializes an `Option<Vec1<T>>` as a (possibly empty) sequence.
+///
+/// An empty sequence is deserialized as `None` and `None` is serialized as an
+/// empty sequence. Combine it with `#[serde(default)]` to also accept a missing
+/// field.
+pub mod empty_as_none {
+    use alloc::vec::Vec;
+
+    use ::serde::{ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Vec1;
+
+    /// Serializes `None` as an empty sequence and `Some` like a `Vec1`.
+    pub fn serialize<T, S>(value: &Option<Vec1<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            Some(vec) => vec.serialize(serializer),
+            None => serializer.serialize_seq(Some(0))?.end(),
+        }
+    }
+
+    /// Deserializes an empty sequence as `None` and any other sequence as `Some`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Vec1<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        Ok(Vec1::try_from_vec(vec).ok())
+    }
+}
+
+/// Deserializes a `Vec1<T>` from either a single value or a non-empty sequence.
+///
+/// Useful for configs which allow both `foo: x` and `foo: [x, y]`. This is
+/// implemented by trying to deserialize a sequence first and a single value
+/// second, so it requires a self-describing format (e.g. JSON or YAML).
+///
+/// Serializing always produces a sequence, use [`one_or_many::prefer_one`]
+/// to serialize a vector with one element as single value instead.
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use vec1::{vec1, Vec1};
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "vec1::serde::one_or_many")]
+///     hosts: Vec1<String>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"hosts": "a"}"#).unwrap();
+/// assert_eq!(config.hosts, vec1!["a".to_owned()]);
+/// let config: Config = serde_json::from_str(r#"{"hosts": ["a", "b"]}"#).unwrap();
+/// assert_eq!(config.hosts.len(), 2);
+/// ```
+pub mod one_or_many {
+    use alloc::vec::Vec;
+
+    use ::serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Vec1;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+
+    /// Serializes the vector as a sequence.
+    pub fn serialize<T, S>(vec: &Vec1<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        vec.serialize(serializer)
+    }
+
+    /// Deserializes the vector from either a single value or a non-empty sequence.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec1<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(value) => Ok(Vec1::new(value)),
+            OneOrMany::Many(vec) => Vec1::try_from_vec(vec).map_err(D::Error::custom),
+        }
+    }
+
+    /// Like [`one_or_many`](crate::serde::one_or_many) but serializes a vector with one element as single value.
+    pub mod prefer_one {
+        use ::serde::{Serialize, Serializer};
+
+        use crate::Vec1;
+
+        pub use super::deserialize;
+
+        /// Serializes a vector with one element as single value and any other vector as sequence.
+        pub fn serialize<T, S>(vec: &Vec1<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Serialize,
+            S: Serializer,
+        {
+            if vec.len() == 1 {
+                vec.first().serialize(serializer)
+            } else {
+                vec.serialize(serializer)
+            }
+        }
+    }
+}
+
+/// (De-)serializes a `Vec1<T>` as a `(first, rest)` tuple.
+///
+/// This makes the non-empty invariant explicit in the wire format, e.g. in
+/// JSON `vec1![1, 2, 3]` is represented as `[1, [2, 3]]` and an empty
+/// vector can't be represented at all.
+///
+/// # Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use vec1::{vec1, Vec1};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Path {
+///     #[serde(with = "vec1::serde::head_tail")]
+///     points: Vec1<u8>,
+/// }
+///
+/// let json = serde_json::to_string(&Path { points: vec1![1, 2, 3] }).unwrap();
+/// assert_eq!(json, r#"{"points":[1,[2,3]]}"#);
+/// let path: Path = serde_json::from_str(r#"{"points":[4,[]]}"#).unwrap();
+/// assert_eq!(path.points, vec1![4]);
+/// ```
+pub mod head_tail {
+    use alloc::vec::Vec;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Vec1;
+
+    /// Serializes the vector as a `(first, rest)` tuple.
+    pub fn serialize<T, S>(vec: &Vec1<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        let (first, rest) = vec.first_and_rest();
+        (first, rest).serialize(serializer)
+    }
+
+    /// Deserializes the vector from a `(first, rest)` tuple.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec1<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let (first, rest) = <(T, Vec<T>)>::deserialize(deserializer)?;
+        let mut vec = Vec1::with_capacity(first, rest.len() + 1);
+        vec.extend(rest);
+        Ok(vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::{vec1, Vec1};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct EmptyAsNone {
+        #[serde(default, with = "crate::serde::empty_as_none")]
+        values: Option<Vec1<u8>>,
+    }
+
+    #[test]
+    fn empty_as_none() {
+        let value: EmptyAsNone = serde_json::from_str(r#"{"values":[]}"#).unwrap();
+        assert_eq!(value, EmptyAsNone { values: None });
+        let value: EmptyAsNone = serde_json::from_str("{}").unwrap();
+        assert_eq!(value, EmptyAsNone { values: None });
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"values":[]}"#);
+
+        let value: EmptyAsNone = serde_json::from_str(r#"{"values":[1,2]}"#).unwrap();
+        assert_eq!(value.values, Some(vec1![1, 2]));
+        let json: String = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"values":[1,2]}"#);
+        assert!(serde_json::from_str::<EmptyAsNone>(r#"{"values":null}"#).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OneOrMany {
+        #[serde(with = "crate::serde::one_or_many")]
+        many: Vec1<u8>,
+        #[serde(with = "crate::serde::one_or_many::prefer_one")]
+        one: Vec1<u8>,
+    }
+
+    #[test]
+    fn one_or_many() {
+        let value: OneOrMany = serde_json::from_str(r#"{"many":1,"one":[2]}"#).unwrap();
+        assert_eq!(value.many, vec1![1]);
+        assert_eq!(value.one, vec1![2]);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"many":[1],"one":2}"#);
+
+        let value: OneOrMany = serde_json::from_str(r#"{"many":[1,2],"one":[3,4]}"#).unwrap();
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"many":[1,2],"one":[3,4]}"#);
+
+        assert!(serde_json::from_str::<OneOrMany>(r#"{"many":[],"one":1}"#).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct HeadTail(#[serde(with = "crate::serde::head_tail")] Vec1<u8>);
+
+    #[test]
+    fn head_tail() {
+        let json = serde_json::to_string(&HeadTail(vec1![1, 2, 3])).unwrap();
+        assert_eq!(json, "[1,[2,3]]");
+        let value: HeadTail = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, HeadTail(vec1![1, 2, 3]));
+        assert_eq!(
+            serde_json::to_string(&HeadTail(vec1![1])).unwrap(),
+            "[1,[]]"
+        );
+        assert!(serde_json::from_str::<HeadTail>("[]").is_err());
+        assert!(serde_json::from_str::<HeadTail>("[[],[]]").is_err());
+    }
+}
@@ -1,4 +1,9 @@
-use core::ops::{Bound, RangeBounds};
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    num::NonZeroUsize,
+    ops::{Bound, RangeBounds},
+};
 
 /// Returns the boolean pair `(covers_all_of_slice, is_out_of_bounds)`.
 ///
@@ -42,6 +47,46 @@ fn range_covers_slice_end(end_bound: Bound<&usize>, len: usize) -> (bool, bool)
     }
 }
 
+/// Returns a mask marking the first occurrence of each distinct element.
+///
+/// Elements are only accessed through `cmp` which compares the elements at two indices.
+/// Uses a stable sort of the indices, so it's `O(n log n)` and the first element is always
+/// marked.
+pub(crate) fn first_occurrence_mask(
+    len: usize,
+    mut cmp: impl FnMut(usize, usize) -> Ordering,
+) -> Vec<bool> {
+    let mut order = (0..len).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| cmp(a, b));
+    let mut mask = alloc::vec![true; len];
+    for pair in order.windows(2) {
+        if cmp(pair[0], pair[1]) == Ordering::Equal {
+            mask[pair[1]] = false;
+        }
+    }
+    mask
+}
+
+/// Returns all elements for which `cmp` returns `Equal` when compared with the greatest element.
+pub(crate) fn max_set_by<'a, T>(
+    first: &'a T,
+    rest: &'a [T],
+    mut cmp: impl FnMut(&T, &T) -> Ordering,
+) -> crate::Vec1<&'a T> {
+    let mut set = crate::Vec1::new(first);
+    for item in rest {
+        match cmp(item, set.first()) {
+            Ordering::Greater => {
+                set.truncate_nonzero(NonZeroUsize::MIN);
+                *set.first_mut() = item;
+            }
+            Ordering::Equal => set.push(item),
+            Ordering::Less => {}
+        }
+    }
+    set
+}
+
 macro_rules! impl_wrapper {
     (
         base_bounds_macro = $($tb:ident : $trait:ident)?,
@@ -64,6 +109,16 @@ macro_rules! impl_wrapper {
     (__PRIV_SELF &self) => (&Self);
 }
 
+// Implements the API shared between `Vec1` and `SmallVec1`.
+//
+// FIXME(v2.0): Replace this macro with a (sealed) `VecBackend` trait and a
+// generic `NonEmpty<B>` core, with `Vec1`/`SmallVec1` being aliases of it.
+// This can't be done in 1.x without a breaking change: the types would change
+// their identity (e.g. for downstream trait impls and type inference of
+// `vec1!`), and methods which are inherent today would either need to move to
+// a trait (requiring an import) or be bound on a public backend trait, which
+// would make the trait part of the SemVer surface. Until then new shared API
+// should be added here, so it stays in sync between both types.
 macro_rules! shared_impl {
     (
         base_bounds_macro = $($tb:ident : $trait:ident)?,
@@ -114,6 +169,52 @@ macro_rules! shared_impl {
                     $name($wrapped::from(vec))
                 }
 
+                /// Creates an instance of length `len` by calling `f` with each index in turn.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use core::num::NonZeroUsize;
+                /// # use vec1::Vec1;
+                /// let v = Vec1::from_fn(NonZeroUsize::new(3).unwrap(), |idx| idx * 2);
+                /// assert_eq!(v, [0, 2, 4]);
+                /// ```
+                pub fn from_fn<F>(len: NonZeroUsize, f: F) -> Self
+                where
+                    F: FnMut(usize) -> $item_ty,
+                {
+                    $name((0..len.get()).map(f).collect())
+                }
+
+                /// Creates an instance containing `len` clones of `element`.
+                ///
+                /// Unlike `try_from_elem` this can't fail as `len` is not 0.
+                pub fn from_elem(element: $item_ty, len: NonZeroUsize) -> Self
+                where
+                    $item_ty: Clone,
+                {
+                    let mut vec = $wrapped::with_capacity(len.get());
+                    vec.resize(len.get(), element);
+                    $name(vec)
+                }
+
+                /// Creates a new instance by repeating the elements `n` times, like `[T]::repeat`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the resulting capacity would overflow.
+                pub fn repeat(&self, n: NonZeroUsize) -> Self
+                where
+                    $item_ty: Clone,
+                {
+                    let capacity = self.len().checked_mul(n.get()).expect("capacity overflow");
+                    let mut vec = $wrapped::with_capacity(capacity);
+                    for _ in 0..n.get() {
+                        vec.extend(self.iter().cloned());
+                    }
+                    $name(vec)
+                }
+
                 /// Creates an instance from a normal `Vec<T>` inserting one additional element.
                 ///
                 /// # Panics
@@ -142,6 +243,38 @@ macro_rules! shared_impl {
                     }
                 }
 
+                /// Creates an instance from a normal `Vec<T>` without checking that it's not empty.
+                ///
+                /// # Safety
+                ///
+                /// The vector must not be empty. The length >= 1 constraint is a logic
+                /// invariant, breaking it can only cause panics (e.g. in `first`), so unsafe
+                /// code must not rely on it.
+                pub unsafe fn from_vec_unchecked(vec: Vec<$item_ty>) -> Self {
+                    debug_assert!(!vec.is_empty());
+                    $name($wrapped::from(vec))
+                }
+
+                /// Tries to create an instance by collecting given iterator.
+                ///
+                /// The items are collected directly into the wrapped type
+                /// without an intermediate `Vec`.
+                ///
+                /// # Errors
+                ///
+                /// This will fail if the iterator doesn't yield any element.
+                pub fn try_from_iter<IT>(iter: IT) -> Result<Self, Size0Error>
+                where
+                    IT: IntoIterator<Item = $item_ty>,
+                {
+                    let wrapped: $wrapped<$t> = iter.into_iter().collect();
+                    if wrapped.is_empty() {
+                        Err(Size0Error)
+                    } else {
+                        Ok($name(wrapped))
+                    }
+                }
+
                 /// Returns a reference to the last element.
                 ///
                 /// As `$name` always contains at least one element there is always a last element.
@@ -231,6 +364,27 @@ macro_rules! shared_impl {
                     }
                 }
 
+                /// Removes and returns the first element in `O(1)` by replacing it with the last element.
+                ///
+                /// This doesn't preserve the order of the remaining elements.
+                ///
+                /// # Errors
+                ///
+                /// If len is 1 an error is returned as the
+                /// length >= 1 constraint must be uphold.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut v = vec1![1, 2, 3, 4];
+                /// assert_eq!(v.swap_remove_front(), Ok(1));
+                /// assert_eq!(v, [4, 2, 3]);
+                /// ```
+                pub fn swap_remove_front(&mut self) -> Result<$item_ty, Size0Error> {
+                    self.swap_remove(0)
+                }
+
                 /// Calls `swap_remove` on the inner smallvec if length >= 2.
                 ///
                 /// # Errors
@@ -279,6 +433,54 @@ macro_rules! shared_impl {
                     self.remove(index)
                 }
 
+                /// Removes the elements at all given indices in a single `O(n)` pass.
+                ///
+                /// # Errors
+                ///
+                /// If this would remove all elements an error is returned **without**
+                /// modifying the vector.
+                ///
+                /// # Panics
+                ///
+                /// If `indices` is not sorted in strictly ascending order (i.e. not
+                /// sorted or containing duplicates) or if any index is out of bounds.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut v = vec1![1, 2, 3, 4, 5];
+                /// v.remove_indices(&[0, 2, 3]).unwrap();
+                /// assert_eq!(v, [2, 5]);
+                /// assert!(v.remove_indices(&[0, 1]).is_err());
+                /// ```
+                pub fn remove_indices(&mut self, indices: &[usize]) -> Result<(), Size0Error> {
+                    let len = self.len();
+                    if let Some(pair) = indices.windows(2).find(|pair| pair[0] >= pair[1]) {
+                        panic!(
+                            "indices must be sorted and deduplicated, found {} before {}",
+                            pair[0], pair[1]
+                        );
+                    }
+                    if let Some(&last) = indices.last() {
+                        assert!(last < len, "index {} is out of bounds for length {}", last, len);
+                    }
+                    if indices.len() >= len {
+                        return Err(Size0Error);
+                    }
+                    let mut next = indices.iter().peekable();
+                    let mut idx = 0;
+                    self.0.retain(|_| {
+                        let remove = next.peek() == Some(&&idx);
+                        if remove {
+                            next.next();
+                        }
+                        idx += 1;
+                        !remove
+                    });
+                    Ok(())
+                }
+
                 /// If calls `drain` on the underlying vector if it will not empty the vector.
                 ///
                 /// # Error
@@ -422,6 +624,108 @@ macro_rules! shared_impl {
                     }
                 }
 
+                /// Runs `f` with mutable access to the inner vector and re-checks the length >= 1
+                /// constraint afterwards.
+                ///
+                /// This gives access to the full API of the inner vector (e.g. `clear` followed
+                /// by refilling it) without needing a dedicated method on this type.
+                ///
+                /// If `f` leaves the inner vector empty a `T::default()` element is pushed as
+                /// sentinel to uphold the length >= 1 constraint, and an error is returned.
+                /// The same happens if `f` panics with the inner vector being empty. Use
+                /// [`Self::edit_or_else()`] if `T` doesn't implement `Default`.
+                ///
+                /// # Panics
+                ///
+                /// If `T::default()` panics the panic is propagated and the vector is left
+                /// empty. If `f` panicked and `T::default()` panics while unwinding the
+                /// process aborts.
+                ///
+                /// # Errors
+                ///
+                /// If `f` left the inner vector empty a `Size0Error` is returned (and the
+                /// value returned by `f` is dropped).
+                ///
+                /// # Example
+                ///
+                /// Is for `Vec1` but similar code works with `SmallVec1`, too.
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                ///
+                /// let mut vec = vec1![1, 2, 3];
+                /// assert!(vec.edit(|vec| vec.clear()).is_err());
+                /// assert_eq!(vec, vec1![0]);
+                /// vec.edit(|vec| {
+                ///     vec.clear();
+                ///     vec.extend([4, 5]);
+                /// }).unwrap();
+                /// assert_eq!(vec, vec1![4, 5]);
+                /// ```
+                pub fn edit<F, R>(&mut self, f: F) -> Result<R, Size0Error>
+                where
+                    F: FnOnce(&mut $wrapped<$t>) -> R,
+                    $item_ty: Default,
+                {
+                    self.edit_or_else(f, Default::default)
+                }
+
+                /// Like [`Self::edit()`] but calls `sentinel` to create the element pushed if
+                /// `f` left the inner vector empty.
+                ///
+                /// # Panics
+                ///
+                /// If `sentinel` panics the panic is propagated and the vector is left empty.
+                /// If `f` panicked and `sentinel` panics while unwinding the process aborts.
+                ///
+                /// # Errors
+                ///
+                /// If `f` left the inner vector empty a `Size0Error` is returned (and the
+                /// value returned by `f` is dropped).
+                pub fn edit_or_else<F, S, R>(&mut self, f: F, sentinel: S) -> Result<R, Size0Error>
+                where
+                    F: FnOnce(&mut $wrapped<$t>) -> R,
+                    S: FnOnce() -> $item_ty,
+                {
+                    // Pushes the sentinel if the inner vector is empty when dropped while
+                    // unwinding, so the length >= 1 constraint is uphold even if `f` panics.
+                    struct RestoreGuard<'a, $t, S>
+                    where
+                        S: FnOnce() -> $item_ty,
+                        $($tb : $trait,)?
+                    {
+                        vec: &'a mut $wrapped<$t>,
+                        sentinel: Option<S>,
+                    }
+
+                    impl<$t, S> Drop for RestoreGuard<'_, $t, S>
+                    where
+                        S: FnOnce() -> $item_ty,
+                        $($tb : $trait,)?
+                    {
+                        fn drop(&mut self) {
+                            if self.vec.is_empty() {
+                                if let Some(sentinel) = self.sentinel.take() {
+                                    self.vec.push(sentinel());
+                                }
+                            }
+                        }
+                    }
+
+                    let mut guard = RestoreGuard { vec: &mut self.0, sentinel: Some(sentinel) };
+                    let result = f(guard.vec);
+                    // Push the sentinel outside of `Drop`, so that a panic in it
+                    // is a normal panic instead of one in a destructor.
+                    let sentinel = guard.sentinel.take();
+                    if guard.vec.is_empty() {
+                        //UNWRAP_SAFE: only taken above
+                        guard.vec.push(sentinel.unwrap()());
+                        Err(Size0Error)
+                    } else {
+                        Ok(result)
+                    }
+                }
+
                 /// Calls `dedup_by_key` on the inner smallvec.
                 ///
                 /// While this can remove elements it will
@@ -447,6 +751,25 @@ macro_rules! shared_impl {
                     self.0.dedup_by(same_bucket)
                 }
 
+                /// Removes all elements with a duplicate key keeping the first occurrence.
+                ///
+                /// See [`Self::unique()`], the key is computed once per element.
+                pub fn unique_by_key<K, F>(&mut self, key: F)
+                where
+                    F: FnMut(&$item_ty) -> K,
+                    K: Ord,
+                {
+                    let keys = self.0.iter().map(key).collect::<Vec<_>>();
+                    let mask = crate::shared::first_occurrence_mask(keys.len(), |a, b| {
+                        keys[a].cmp(&keys[b])
+                    });
+                    let mut idx = 0;
+                    self.0.retain(|_| {
+                        idx += 1;
+                        mask[idx - 1]
+                    });
+                }
+
                 /// Remove the last element from this vector, if there is more than one element in it.
                 ///
                 /// # Errors
@@ -462,6 +785,32 @@ macro_rules! shared_impl {
                     }
                 }
 
+                /// Removes the last `n` elements and returns them in their original order.
+                ///
+                /// *Like `pop` this isn't `try_` prefixed even though it can fail.*
+                ///
+                /// # Errors
+                ///
+                /// If `n >= len` an error is returned **without** modifying the vector.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut v = vec1![1, 2, 3, 4];
+                /// assert_eq!(v.pop_many(2), Ok(vec![3, 4]));
+                /// assert_eq!(v, [1, 2]);
+                /// assert!(v.pop_many(2).is_err());
+                /// ```
+                pub fn pop_many(&mut self, n: usize) -> Result<Vec<$item_ty>, Size0Error> {
+                    let len = self.len();
+                    if n < len {
+                        Ok(self.0.drain(len - n..).collect())
+                    } else {
+                        Err(Size0Error)
+                    }
+                }
+
                 /// Remove the last element from this vector, if there is more than one element in it.
                 ///
                 /// # Errors
@@ -528,91 +877,638 @@ macro_rules! shared_impl {
                     (smallvec, last)
                 }
 
-                /// Turns this vector into a boxed slice.
+                /// Returns a reference to the first `N` elements as array, or `None` if `len < N`.
                 ///
-                /// For `Vec1` this is as cheap as for `Vec` but for
-                /// `SmallVec1` this will cause an allocation if the
-                /// on-stack buffer was not yet spilled.
-                pub fn into_boxed_slice(self) -> Box<[$item_ty]> {
-                    self.into_vec().into_boxed_slice()
+                /// Like `slice::first_chunk()`, which isn't available on all supported rust versions.
+                pub fn first_chunk<const N: usize>(&self) -> Option<&[$item_ty; N]> {
+                    self.0.get(..N).and_then(|chunk| chunk.try_into().ok())
                 }
 
-                /// Leaks the allocation to return a mutable slice reference.
+                /// Returns a mutable reference to the first `N` elements as array, or `None` if `len < N`.
+                pub fn first_chunk_mut<const N: usize>(&mut self) -> Option<&mut [$item_ty; N]> {
+                    self.0.get_mut(..N).and_then(|chunk| chunk.try_into().ok())
+                }
+
+                /// Returns a reference to the last `N` elements as array, or `None` if `len < N`.
                 ///
-                /// This is equivalent to turning this vector into a boxed
-                /// slice and then leaking that slice.
+                /// Like `slice::last_chunk()`, which isn't available on all supported rust versions.
+                pub fn last_chunk<const N: usize>(&self) -> Option<&[$item_ty; N]> {
+                    let start = self.len().checked_sub(N)?;
+                    self.0[start..].try_into().ok()
+                }
+
+                /// Returns a mutable reference to the last `N` elements as array, or `None` if `len < N`.
+                pub fn last_chunk_mut<const N: usize>(&mut self) -> Option<&mut [$item_ty; N]> {
+                    let start = self.len().checked_sub(N)?;
+                    (&mut self.0[start..]).try_into().ok()
+                }
+
+                /// Returns the first element as array of length 1.
                 ///
-                /// In case of `SmallVec1` calling leak does entail an allocation
-                /// if the stack-buffer had not yet spilled.
-                pub fn leak<'a>(self) -> &'a mut [$item_ty]
+                /// Infallible version of `first_chunk::<1>()`.
+                pub fn first_as_array(&self) -> &[$item_ty; 1] {
+                    core::array::from_ref(self.first())
+                }
+
+                /// Returns the last element as array of length 1.
+                ///
+                /// Infallible version of `last_chunk::<1>()`.
+                pub fn last_as_array(&self) -> &[$item_ty; 1] {
+                    core::array::from_ref(self.last())
+                }
+
+                /// Returns a reference to the first element and a slice of the remaining elements.
+                ///
+                /// Like [`slice::split_first()`] but without the `Option` and without consuming
+                /// the vector like `split_off_first` does.
+                pub fn first_and_rest(&self) -> (&$item_ty, &[$item_ty]) {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.0.split_first().unwrap()
+                }
+
+                /// Returns a mutable reference to the first element and a mutable slice of the
+                /// remaining elements.
+                pub fn first_and_rest_mut(&mut self) -> (&mut $item_ty, &mut [$item_ty]) {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.0.split_first_mut().unwrap()
+                }
+
+                /// Returns a slice of all but the last element and a reference to the last element.
+                ///
+                /// Like [`slice::split_last()`] but without the `Option` and without consuming
+                /// the vector like `split_off_last` does.
+                pub fn rest_and_last(&self) -> (&[$item_ty], &$item_ty) {
+                    //UNWRAP_SAFE: len is at least 1
+                    let (last, rest) = self.0.split_last().unwrap();
+                    (rest, last)
+                }
+
+                /// Returns a mutable slice of all but the last element and a mutable reference to
+                /// the last element.
+                pub fn rest_and_last_mut(&mut self) -> (&mut [$item_ty], &mut $item_ty) {
+                    //UNWRAP_SAFE: len is at least 1
+                    let (last, rest) = self.0.split_last_mut().unwrap();
+                    (rest, last)
+                }
+
+                /// Mutates all elements in place, stopping at the first error.
+                ///
+                /// Unlike `try_mapped` this doesn't consume the vector, so no data is
+                /// lost if `map_fn` fails.
+                ///
+                /// # Errors
+                ///
+                /// Once any call to `map_fn` returns a error that error is directly
+                /// returned by this method. All elements before the failing one have
+                /// been mutated, all elements after it have not been touched and the
+                /// failing element is in whatever state `map_fn` left it in.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut v = vec1![1u8, 200, 3];
+                /// let res = v.try_map_in_place(|x| {
+                ///     *x = x.checked_add(100).ok_or("overflow")?;
+                ///     Ok(())
+                /// });
+                /// assert_eq!(res, Err("overflow"));
+                /// assert_eq!(v, [101, 200, 3]);
+                /// ```
+                pub fn try_map_in_place<F, E>(&mut self, mut map_fn: F) -> Result<(), E>
                 where
-                    $item_ty: 'a
+                    F: FnMut(&mut $item_ty) -> Result<(), E>,
                 {
-                    self.into_vec().leak()
+                    for item in self.0.iter_mut() {
+                        map_fn(item)?;
+                    }
+                    Ok(())
                 }
 
-                /// Like [`Iterator::reduce()`] but does not return an option.
+                /// Replaces each element with the value computed from it, stopping at the first error.
                 ///
-                /// This is roughly equivalent with `.into_iter().reduce(f).unwrap()`.
+                /// This is the "by value" flavor of [`Self::try_map_in_place()`]. The new
+                /// value is computed from a reference, as passing the element by value
+                /// would mean it is lost if `map_fn` fails.
+                ///
+                /// # Errors
+                ///
+                /// Once any call to `map_fn` returns a error that error is directly
+                /// returned by this method. All elements before the failing one have
+                /// been replaced, the failing element and all elements after it are
+                /// unchanged.
                 ///
                 /// # Example
                 ///
                 /// ```
                 /// # use vec1::vec1;
-                /// assert_eq!(vec1![1,2,4,3].reduce(std::cmp::max), 4)
+                /// let mut v = vec1![1u8, 200, 3];
+                /// let res = v.try_replace_in_place(|x| x.checked_add(100).ok_or("overflow"));
+                /// assert_eq!(res, Err("overflow"));
+                /// assert_eq!(v, [101, 200, 3]);
                 /// ```
+                pub fn try_replace_in_place<F, E>(&mut self, mut map_fn: F) -> Result<(), E>
+                where
+                    F: FnMut(&$item_ty) -> Result<$item_ty, E>,
+                {
+                    for item in self.0.iter_mut() {
+                        *item = map_fn(item)?;
+                    }
+                    Ok(())
+                }
+
+                /// Creates a `Vec1` of running accumulations (an inclusive scan).
                 ///
-                /// *Be aware that `reduce` consumes the vector, to get a reference
-                /// use either `reduce_ref` or `reduce_mut`.*
+                /// The first output is `init(first)`, each following output is computed
+                /// by `f` from the previous output and the next element. So the result
+                /// has the same length as `self`.
                 ///
-                pub fn reduce(self, f: impl FnMut($item_ty, $item_ty) -> $item_ty) -> $item_ty {
-                    //UNWRAP_SAFE: len is at least 1
-                    self.into_iter().reduce(f).unwrap()
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let prefix_sums = vec1![1, 2, 3, 4].scan1(|first| *first, |acc, x| acc + x);
+                /// assert_eq!(prefix_sums, vec1![1, 3, 6, 10]);
+                /// ```
+                pub fn scan1<N, IN, F>(&self, init: IN, mut f: F) -> crate::Vec1<N>
+                where
+                    IN: FnOnce(&$item_ty) -> N,
+                    F: FnMut(&N, &$item_ty) -> N,
+                {
+                    let (first, rest) = self.first_and_rest();
+                    let mut out = crate::Vec1::with_capacity(init(first), self.len());
+                    for item in rest {
+                        let next = f(out.last(), item);
+                        out.push(next);
+                    }
+                    out
                 }
 
-                /// Like [`Iterator::reduce()`] but does not return an option.
-                ///
-                /// This is roughly equivalent with `.iter().reduce(f).unwrap()`.
+                /// Merges adjacent elements for which `f` returns `Ok`.
                 ///
-                /// *Hint: Because of the reduction function returning a reference
-                /// this method is (in general) only suitable for selecting exactly
-                /// one element from the vector.*
+                /// Like itertools `coalesce`: `f` is called with the current (possibly
+                /// already merged) element and the next element. If it returns `Ok`
+                /// the merged element replaces both, if it returns `Err` with both
+                /// elements the first one is kept as is and the second one becomes
+                /// the current element.
                 ///
                 /// # Example
                 ///
                 /// ```
                 /// # use vec1::vec1;
-                /// assert_eq!(vec1![1,2,4,3].reduce_ref(std::cmp::max), &4)
+                /// let ranges = vec1![(1, 3), (2, 5), (7, 8), (8, 9)];
+                /// let merged = ranges.coalesce(|a, b| {
+                ///     if b.0 <= a.1 { Ok((a.0, a.1.max(b.1))) } else { Err((a, b)) }
+                /// });
+                /// assert_eq!(merged, vec1![(1, 5), (7, 9)]);
                 /// ```
-                ///
-                pub fn reduce_ref<'a>(&'a self, f: impl FnMut(&'a $item_ty, &'a $item_ty) -> &'a $item_ty) -> &'a $item_ty {
+                pub fn coalesce<F>(self, mut f: F) -> Self
+                where
+                    F: FnMut($item_ty, $item_ty) -> Result<$item_ty, ($item_ty, $item_ty)>,
+                {
+                    let mut iter = self.0.into_iter();
                     //UNWRAP_SAFE: len is at least 1
-                    self.iter().reduce(f).unwrap()
+                    let mut current = iter.next().unwrap();
+                    let mut out = $wrapped::new();
+                    for next in iter {
+                        match f(current, next) {
+                            Ok(merged) => current = merged,
+                            Err((done, next)) => {
+                                out.push(done);
+                                current = next;
+                            }
+                        }
+                    }
+                    out.push(current);
+                    $name(out)
                 }
 
-                /// Like [`Iterator::reduce()`] but does not return an option.
-                ///
-                /// This is roughly equivalent with `.iter_mut().reduce(f).unwrap()`.
-                ///
-                /// *Hint: Because of the reduction function returning a reference
-                /// this method is (in general) only suitable for selecting exactly
-                /// one element from the vector.*
+                /// Pushes `value` and returns the vector, for use in expression position.
                 ///
                 /// # Example
                 ///
                 /// ```
                 /// # use vec1::vec1;
-                /// assert_eq!(vec1![1,2,4,3].reduce_mut(std::cmp::max), &mut 4)
+                /// let v = vec1![1].pushed(2).appended(vec![3, 4]).reversed();
+                /// assert_eq!(v, [4, 3, 2, 1]);
                 /// ```
-                ///
-                pub fn reduce_mut<'a>(&'a mut self, f: impl FnMut(&'a mut $item_ty, &'a mut $item_ty) -> &'a mut $item_ty) -> &'a mut $item_ty {
-                    //UNWRAP_SAFE: len is at least 1
-                    self.iter_mut().reduce(f).unwrap()
+                pub fn pushed(mut self, value: $item_ty) -> Self {
+                    self.0.push(value);
+                    self
                 }
 
-            }
+                /// Moves all elements of `other` to the end and returns the vector.
+                pub fn appended(mut self, mut other: $wrapped<$t>) -> Self {
+                    self.0.append(&mut other);
+                    self
+                }
 
-            // methods in Vec not in &[] which can be directly exposed
+                /// Reverses the order of the elements and returns the vector.
+                pub fn reversed(mut self) -> Self {
+                    self.0.reverse();
+                    self
+                }
+
+                /// Sorts the elements (stable) and returns the vector.
+                pub fn sorted(mut self) -> Self
+                where
+                    $item_ty: Ord,
+                {
+                    self.0.sort();
+                    self
+                }
+
+                /// Returns an iterator over all pairs of adjacent elements.
+                ///
+                /// Like `windows(2)` but yields tuples. If the length is 1 the
+                /// iterator is empty, otherwise it yields `len - 1` pairs.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let v = vec1![1, 4, 9];
+                /// let deltas: Vec<i32> = v.pairs().map(|(a, b)| b - a).collect();
+                /// assert_eq!(deltas, [3, 5]);
+                /// assert_eq!(vec1![1].pairs().count(), 0);
+                /// ```
+                pub fn pairs(
+                    &self,
+                ) -> impl ExactSizeIterator<Item = (&$item_ty, &$item_ty)> + DoubleEndedIterator + '_ {
+                    self.windows(2).map(|pair| (&pair[0], &pair[1]))
+                }
+
+                /// Returns the element at `index`, or the last element if `index` is out of bounds.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let v = vec1![1, 2, 3];
+                /// assert_eq!(v.get_clamped(1), &2);
+                /// assert_eq!(v.get_clamped(10), &3);
+                /// ```
+                pub fn get_clamped(&self, index: usize) -> &$item_ty {
+                    &self.0[index.min(self.len() - 1)]
+                }
+
+                /// Returns the element at `index` mutably, or the last element if `index` is out of bounds.
+                pub fn get_clamped_mut(&mut self, index: usize) -> &mut $item_ty {
+                    let last = self.len() - 1;
+                    &mut self.0[index.min(last)]
+                }
+
+                /// Returns references to the first and the last element.
+                ///
+                /// If the length is 1 both references point to the same element.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![1, 2, 3].ends(), (&1, &3));
+                /// assert_eq!(vec1![1].ends(), (&1, &1));
+                /// ```
+                pub fn ends(&self) -> (&$item_ty, &$item_ty) {
+                    (self.first(), self.last())
+                }
+
+                /// Returns mutable references to the first and the last element.
+                ///
+                /// If the length is 1 the first and the last element are the same
+                /// element, in which case `None` is returned for the last element.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let mut v = vec1![1, 2, 3];
+                /// let (first, last) = v.ends_mut();
+                /// std::mem::swap(first, last.unwrap());
+                /// assert_eq!(v, [3, 2, 1]);
+                ///
+                /// let mut v = vec1![1];
+                /// assert_eq!(v.ends_mut(), (&mut 1, None));
+                /// ```
+                pub fn ends_mut(&mut self) -> (&mut $item_ty, Option<&mut $item_ty>) {
+                    let (first, rest) = self.first_and_rest_mut();
+                    (first, rest.last_mut())
+                }
+
+                /// Turns this vector into a boxed slice.
+                ///
+                /// For `Vec1` this is as cheap as for `Vec` but for
+                /// `SmallVec1` this will cause an allocation if the
+                /// on-stack buffer was not yet spilled.
+                pub fn into_boxed_slice(self) -> Box<[$item_ty]> {
+                    self.into_vec().into_boxed_slice()
+                }
+
+                /// Turns this vector into a boxed non-empty slice.
+                ///
+                /// Like [`Self::into_boxed_slice()`] this drops any excess capacity.
+                pub fn into_boxed_slice1(self) -> Box<crate::Slice1<$item_ty>> {
+                    // SAFETY: only a layout cast, a (leak-)emptied vector can only cause panics
+                    unsafe { crate::Slice1::from_boxed_slice_unchecked(self.into_boxed_slice()) }
+                }
+
+                /// Leaks the allocation to return a mutable slice reference.
+                ///
+                /// This is equivalent to turning this vector into a boxed
+                /// slice and then leaking that slice.
+                ///
+                /// In case of `SmallVec1` calling leak does entail an allocation
+                /// if the stack-buffer had not yet spilled.
+                pub fn leak<'a>(self) -> &'a mut [$item_ty]
+                where
+                    $item_ty: 'a
+                {
+                    self.into_vec().leak()
+                }
+
+                /// Returns the elements as a non-empty `Slice1`.
+                ///
+                /// *There is no `Index<RangeFull>` returning a `Slice1`, as it would
+                /// conflict with the generic `Index` impl delegating to the slice.*
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::{vec1, Slice1};
+                /// fn first_of(values: &Slice1<u8>) -> u8 {
+                ///     *values.first()
+                /// }
+                /// assert_eq!(first_of(vec1![3, 4].as_slice1()), 3);
+                /// ```
+                pub fn as_slice1(&self) -> &crate::Slice1<$item_ty> {
+                    // SAFETY: only a layout cast, a (leak-)emptied vector can only cause panics
+                    unsafe { crate::Slice1::from_slice_unchecked(self.as_slice()) }
+                }
+
+                /// Returns the elements as a mutable non-empty `Slice1`.
+                pub fn as_mut_slice1(&mut self) -> &mut crate::Slice1<$item_ty> {
+                    // SAFETY: only a layout cast, a (leak-)emptied vector can only cause panics
+                    unsafe { crate::Slice1::from_mut_slice_unchecked(self.as_mut_slice()) }
+                }
+
+                /// Returns a non-empty iterator over references to the elements.
+                pub fn iter1(&self) -> crate::Iterator1<core::slice::Iter<'_, $item_ty>> {
+                    // SAFETY: len is at least 1
+                    unsafe { crate::Iterator1::new_unchecked(self.iter()) }
+                }
+
+                /// Returns a non-empty iterator over mutable references to the elements.
+                pub fn iter1_mut(&mut self) -> crate::Iterator1<core::slice::IterMut<'_, $item_ty>> {
+                    // SAFETY: len is at least 1
+                    unsafe { crate::Iterator1::new_unchecked(self.iter_mut()) }
+                }
+
+                /// Turns this vector into a non-empty iterator over its elements.
+                pub fn into_iter1(self) -> crate::Iterator1<<Self as IntoIterator>::IntoIter> {
+                    // SAFETY: len is at least 1
+                    unsafe { crate::Iterator1::new_unchecked(self.into_iter()) }
+                }
+
+                /// Like `leak` but returns a non-empty `Slice1`.
+                ///
+                /// So the non-empty guarantee is kept for the rest of the program.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::{vec1, Slice1};
+                /// let table: &'static mut Slice1<u8> = vec1![1, 2].leak1();
+                /// assert_eq!(table.first(), &1);
+                /// ```
+                pub fn leak1<'a>(self) -> &'a mut crate::Slice1<$item_ty>
+                where
+                    $item_ty: 'a
+                {
+                    // SAFETY: only a layout cast, a (leak-)emptied vector can only cause panics
+                    unsafe { crate::Slice1::from_mut_slice_unchecked(self.leak()) }
+                }
+
+                /// Like [`Iterator::reduce()`] but does not return an option.
+                ///
+                /// This is roughly equivalent with `.into_iter().reduce(f).unwrap()`.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![1,2,4,3].reduce(std::cmp::max), 4)
+                /// ```
+                ///
+                /// *Be aware that `reduce` consumes the vector, to get a reference
+                /// use either `reduce_ref` or `reduce_mut`.*
+                ///
+                pub fn reduce(self, f: impl FnMut($item_ty, $item_ty) -> $item_ty) -> $item_ty {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.into_iter().reduce(f).unwrap()
+                }
+
+                /// Like [`Iterator::reduce()`] but does not return an option.
+                ///
+                /// This is roughly equivalent with `.iter().reduce(f).unwrap()`.
+                ///
+                /// *Hint: Because of the reduction function returning a reference
+                /// this method is (in general) only suitable for selecting exactly
+                /// one element from the vector.*
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![1,2,4,3].reduce_ref(std::cmp::max), &4)
+                /// ```
+                ///
+                pub fn reduce_ref<'a>(&'a self, f: impl FnMut(&'a $item_ty, &'a $item_ty) -> &'a $item_ty) -> &'a $item_ty {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter().reduce(f).unwrap()
+                }
+
+                /// Like [`Iterator::reduce()`] but does not return an option.
+                ///
+                /// This is roughly equivalent with `.iter_mut().reduce(f).unwrap()`.
+                ///
+                /// *Hint: Because of the reduction function returning a reference
+                /// this method is (in general) only suitable for selecting exactly
+                /// one element from the vector.*
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![1,2,4,3].reduce_mut(std::cmp::max), &mut 4)
+                /// ```
+                ///
+                pub fn reduce_mut<'a>(&'a mut self, f: impl FnMut(&'a mut $item_ty, &'a mut $item_ty) -> &'a mut $item_ty) -> &'a mut $item_ty {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter_mut().reduce(f).unwrap()
+                }
+
+                /// Returns the maximum element, like [`Iterator::max()`] but without the `Option`.
+                ///
+                /// If several elements are equally maximum, the last element is returned.
+                ///
+                /// *This isn't called `max` as `Ord::max` would take precedence
+                /// during method resolution.*
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![1, 4, 2].max_element(), &4);
+                /// ```
+                pub fn max_element(&self) -> &$item_ty
+                where
+                    $item_ty: Ord,
+                {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter().max().unwrap()
+                }
+
+                /// Returns the minimum element, like [`Iterator::min()`] but without the `Option`.
+                ///
+                /// If several elements are equally minimum, the first element is returned.
+                ///
+                /// *This isn't called `min` as `Ord::min` would take precedence
+                /// during method resolution.*
+                pub fn min_element(&self) -> &$item_ty
+                where
+                    $item_ty: Ord,
+                {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter().min().unwrap()
+                }
+
+                /// Returns the element with the maximum key, like [`Iterator::max_by_key()`]
+                /// but without the `Option`.
+                ///
+                /// If several elements are equally maximum, the last element is returned.
+                pub fn max_by_key<K, F>(&self, mut key: F) -> &$item_ty
+                where
+                    F: FnMut(&$item_ty) -> K,
+                    K: Ord,
+                {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter().max_by_key(|item| key(item)).unwrap()
+                }
+
+                /// Returns the element with the minimum key, like [`Iterator::min_by_key()`]
+                /// but without the `Option`.
+                ///
+                /// If several elements are equally minimum, the first element is returned.
+                pub fn min_by_key<K, F>(&self, mut key: F) -> &$item_ty
+                where
+                    F: FnMut(&$item_ty) -> K,
+                    K: Ord,
+                {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter().min_by_key(|item| key(item)).unwrap()
+                }
+
+                /// Returns the maximum element with respect to `compare`, like [`Iterator::max_by()`]
+                /// but without the `Option`.
+                ///
+                /// If several elements are equally maximum, the last element is returned.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// let v = vec1![1.5f64, -3.0, 2.25];
+                /// assert_eq!(v.max_by(|a, b| a.total_cmp(b)), &2.25);
+                /// assert_eq!(v.min_by(|a, b| a.total_cmp(b)), &-3.0);
+                /// ```
+                pub fn max_by<F>(&self, mut compare: F) -> &$item_ty
+                where
+                    F: FnMut(&$item_ty, &$item_ty) -> core::cmp::Ordering,
+                {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter().max_by(|a, b| compare(a, b)).unwrap()
+                }
+
+                /// Returns the minimum element with respect to `compare`, like [`Iterator::min_by()`]
+                /// but without the `Option`.
+                ///
+                /// If several elements are equally minimum, the first element is returned.
+                pub fn min_by<F>(&self, mut compare: F) -> &$item_ty
+                where
+                    F: FnMut(&$item_ty, &$item_ty) -> core::cmp::Ordering,
+                {
+                    //UNWRAP_SAFE: len is at least 1
+                    self.iter().min_by(|a, b| compare(a, b)).unwrap()
+                }
+
+                /// Returns all elements equal to the minimum, in the order they appear in.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![2, 1, 3, 1].min_set(), vec1![&1, &1]);
+                /// ```
+                pub fn min_set(&self) -> crate::Vec1<&$item_ty>
+                where
+                    $item_ty: Ord,
+                {
+                    self.min_set_by(Ord::cmp)
+                }
+
+                /// Returns all elements equal to the maximum, in the order they appear in.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// # use vec1::vec1;
+                /// assert_eq!(vec1![3, 1, 3, 2].max_set(), vec1![&3, &3]);
+                /// ```
+                pub fn max_set(&self) -> crate::Vec1<&$item_ty>
+                where
+                    $item_ty: Ord,
+                {
+                    self.max_set_by(Ord::cmp)
+                }
+
+                /// Like [`Self::min_set()`] but using the given comparison function.
+                pub fn min_set_by<F>(&self, mut compare: F) -> crate::Vec1<&$item_ty>
+                where
+                    F: FnMut(&$item_ty, &$item_ty) -> core::cmp::Ordering,
+                {
+                    let (first, rest) = self.first_and_rest();
+                    crate::shared::max_set_by(first, rest, |a, b| compare(b, a))
+                }
+
+                /// Like [`Self::max_set()`] but using the given comparison function.
+                pub fn max_set_by<F>(&self, compare: F) -> crate::Vec1<&$item_ty>
+                where
+                    F: FnMut(&$item_ty, &$item_ty) -> core::cmp::Ordering,
+                {
+                    let (first, rest) = self.first_and_rest();
+                    crate::shared::max_set_by(first, rest, compare)
+                }
+
+                /// Like [`Self::min_set()`] but comparing the keys returned by `key`.
+                pub fn min_set_by_key<K, F>(&self, mut key: F) -> crate::Vec1<&$item_ty>
+                where
+                    F: FnMut(&$item_ty) -> K,
+                    K: Ord,
+                {
+                    self.min_set_by(|a, b| key(a).cmp(&key(b)))
+                }
+
+                /// Like [`Self::max_set()`] but comparing the keys returned by `key`.
+                pub fn max_set_by_key<K, F>(&self, mut key: F) -> crate::Vec1<&$item_ty>
+                where
+                    F: FnMut(&$item_ty) -> K,
+                    K: Ord,
+                {
+                    self.max_set_by(|a, b| key(a).cmp(&key(b)))
+                }
+            }
+
+            // methods in Vec not in &[] which can be directly exposed
             impl_wrapper! {
                 base_bounds_macro = $($tb : $trait)?,
                 impl<$t> $name<$t> {
@@ -639,6 +1535,50 @@ macro_rules! shared_impl {
                 }
             }
 
+            impl<$t> $name<$t>
+            where
+                $item_ty: Ord,
+                $($tb : $trait,)?
+            {
+                /// Sorts the vector and then removes all duplicates.
+                ///
+                /// Uses a unstable sort, returns `self` to allow chaining.
+                ///
+                /// Like `dedup` this can never produce a empty vector.
+                pub fn sort_and_dedup(&mut self) -> &mut Self {
+                    self.0.sort_unstable();
+                    self.0.dedup();
+                    self
+                }
+
+                /// Removes all duplicates keeping the first occurrence of each element.
+                ///
+                /// Unlike `dedup` this also removes non-consecutive duplicates and
+                /// unlike `sort_and_dedup` it preserves the order of the elements.
+                ///
+                /// This is `O(n log n)`, it sorts a list of indices internally.
+                pub fn unique(&mut self) {
+                    let mask = crate::shared::first_occurrence_mask(self.len(), |a, b| {
+                        self.0[a].cmp(&self.0[b])
+                    });
+                    let mut idx = 0;
+                    self.0.retain(|_| {
+                        idx += 1;
+                        mask[idx - 1]
+                    });
+                }
+
+                /// Like [`Self::sort_and_dedup()`] but uses a stable sort.
+                ///
+                /// So from elements which compare equal the first one
+                /// (in the original order) is kept.
+                pub fn sort_stable_and_dedup(&mut self) -> &mut Self {
+                    self.0.sort();
+                    self.0.dedup();
+                    self
+                }
+            }
+
             impl<$t> $name<$t>
             where
                 $item_ty: Copy,
@@ -720,6 +1660,18 @@ macro_rules! shared_impl {
                 }
             }
 
+            impl<$t> crate::TryFromIterator<$item_ty> for $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn try_from_iter<IT>(iter: IT) -> Result<Self, Size0Error>
+                where
+                    IT: IntoIterator<Item = $item_ty>,
+                {
+                    $name::try_from_iter(iter)
+                }
+            }
+
             impl<$t> TryFrom<Box<[$item_ty]>> for $name<$t>
             where
                 $($tb : $trait,)?
@@ -852,6 +1804,75 @@ macro_rules! shared_impl {
                 }
             }
 
+            impl<$t> crate::non_empty::sealed::Sealed for $name<$t>
+            where
+                $($tb : $trait,)?
+            {}
+
+            impl<$t> crate::NonEmptyCollection for $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                type Item = $item_ty;
+
+                fn new(first: $item_ty) -> Self {
+                    $name::new(first)
+                }
+
+                fn try_from_vec(vec: Vec<$item_ty>) -> Result<Self, Size0Error> {
+                    $name::try_from_vec(vec)
+                }
+
+                fn into_vec(self) -> Vec<$item_ty> {
+                    $name::into_vec(self)
+                }
+
+                fn as_slice1(&self) -> &crate::Slice1<$item_ty> {
+                    $name::as_slice1(self)
+                }
+
+                fn as_mut_slice1(&mut self) -> &mut crate::Slice1<$item_ty> {
+                    $name::as_mut_slice1(self)
+                }
+
+                fn push(&mut self, value: $item_ty) {
+                    $name::push(self, value)
+                }
+
+                fn pop(&mut self) -> Result<$item_ty, Size0Error> {
+                    $name::pop(self)
+                }
+            }
+
+            impl<$t> crate::IntoIterator1 for $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn into_iter1(self) -> crate::Iterator1<Self::IntoIter> {
+                    // SAFETY: len is at least 1
+                    unsafe { crate::Iterator1::new_unchecked(self.into_iter()) }
+                }
+            }
+
+            impl<'a, $t> crate::IntoIterator1 for &'a $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn into_iter1(self) -> crate::Iterator1<Self::IntoIter> {
+                    self.iter1()
+                }
+            }
+
+            impl<'a, $t> crate::IntoIterator1 for &'a mut $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn into_iter1(self) -> crate::Iterator1<Self::IntoIter> {
+                    self.iter1_mut()
+                }
+            }
+
+            #[cfg(not(feature = "disable-default-impl"))]
             impl<$t> Default for $name<$t>
             where
                 $item_ty: Default,
@@ -928,6 +1949,24 @@ macro_rules! shared_impl {
                 }
             }
 
+            impl<$t> AsRef<crate::Slice1<$item_ty>> for $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn as_ref(&self) -> &crate::Slice1<$item_ty> {
+                    self.as_slice1()
+                }
+            }
+
+            impl<$t> AsMut<crate::Slice1<$item_ty>> for $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn as_mut(&mut self) -> &mut crate::Slice1<$item_ty> {
+                    self.as_mut_slice1()
+                }
+            }
+
             impl<$t, SI> Index<SI> for $name<$t>
             where
                 SI: SliceIndex<[$item_ty]>,
@@ -969,12 +2008,410 @@ macro_rules! shared_impl {
                 }
             }
 
+            #[cfg(feature = "fake")]
+            const _: () = {
+                use fake::{rand::Rng, Dummy, Fake, Faker};
+
+                /// Generates between 1 and 9 elements.
+                ///
+                /// This is the same length range `fake` uses for `Vec<T>`
+                /// except that it will never produce an empty vector.
+                impl<$t> Dummy<Faker> for $name<$t>
+                where
+                    $item_ty: Dummy<Faker>,
+                    $($tb : $trait,)?
+                {
+                    fn dummy_with_rng<R: Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+                        let len: usize = (1..10).fake_with_rng(rng);
+                        let mut vec = $wrapped::with_capacity(len);
+                        for _ in 0..len {
+                            vec.push(config.fake_with_rng(rng));
+                        }
+                        $name(vec)
+                    }
+                }
+
+                /// Generates elements using the config `E` and the length using the config `L`.
+                ///
+                /// The generated length is clamped to be at least 1, i.e. a length range
+                /// of `0..5` behaves like `1..5` except that `1` is more likely to be picked.
+                impl<$t, E, L> Dummy<(E, L)> for $name<$t>
+                where
+                    $item_ty: Dummy<E>,
+                    usize: Dummy<L>,
+                    $($tb : $trait,)?
+                {
+                    fn dummy_with_rng<R: Rng + ?Sized>(config: &(E, L), rng: &mut R) -> Self {
+                        let len = config.1.fake_with_rng::<usize, _>(rng).max(1);
+                        let mut vec = $wrapped::with_capacity(len);
+                        for _ in 0..len {
+                            vec.push(config.0.fake_with_rng(rng));
+                        }
+                        $name(vec)
+                    }
+                }
+            };
+
+            #[cfg(feature = "ufmt")]
+            impl<$t> ufmt::uDebug for $name<$t>
+            where
+                $($tb : $trait,)?
+                $item_ty: ufmt::uDebug,
+            {
+                fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: ufmt::uWrite + ?Sized,
+                {
+                    <[$item_ty] as ufmt::uDebug>::fmt(&self.0, f)
+                }
+            }
+
+            #[cfg(feature = "defmt")]
+            impl<$t> defmt::Format for $name<$t>
+            where
+                $($tb : $trait,)?
+                $item_ty: defmt::Format,
+            {
+                fn format(&self, fmt: defmt::Formatter<'_>) {
+                    <[$item_ty] as defmt::Format>::format(&self.0, fmt)
+                }
+            }
+
+            #[cfg(feature = "musli")]
+            const _: () = {
+                use musli::{
+                    de::{Decode, Decoder},
+                    en::{Encode, Encoder, SequenceEncoder},
+                    mode::Mode,
+                    Context,
+                };
+
+                impl<M, $t> Encode<M> for $name<$t>
+                where
+                    M: Mode,
+                    $item_ty: Encode<M>,
+                    $($tb : $trait,)?
+                {
+                    fn encode<C, E>(&self, cx: &mut C, encoder: E) -> Result<E::Ok, C::Error>
+                    where
+                        C: Context<Input = E::Error>,
+                        E: Encoder,
+                    {
+                        let mut seq = encoder.encode_sequence(cx, self.len())?;
+                        for (index, value) in self.iter().enumerate() {
+                            cx.enter_sequence_index(index);
+                            let encoder = seq.next(cx)?;
+                            value.encode(cx, encoder)?;
+                            cx.leave_sequence_index();
+                        }
+                        seq.end(cx)
+                    }
+                }
+
+                impl<'de, M, $t> Decode<'de, M> for $name<$t>
+                where
+                    M: Mode,
+                    $item_ty: Decode<'de, M>,
+                    $($tb : $trait,)?
+                {
+                    fn decode<C, D>(cx: &mut C, decoder: D) -> Result<Self, C::Error>
+                    where
+                        C: Context<Input = D::Error>,
+                        D: Decoder<'de>,
+                    {
+                        let vec = <Vec<$item_ty> as Decode<'de, M>>::decode(cx, decoder)?;
+                        $name::try_from_vec(vec).map_err(|err| cx.custom(err))
+                    }
+                }
+            };
+
+            #[cfg(feature = "rand")]
+            const _: () = {
+                use rand::{
+                    distr::{
+                        uniform::{SampleBorrow, SampleUniform},
+                        weighted::{Error as WeightError, Weight},
+                    },
+                    seq::{IndexedMutRandom, IndexedRandom, SliceRandom},
+                    Rng,
+                };
+
+                impl<$t> $name<$t>
+                where
+                    $($tb : $trait,)?
+                {
+                    /// Chooses one element uniformly at random.
+                    ///
+                    /// Like `[T]::choose` but as the vector is never empty no `Option` is returned.
+                    ///
+                    /// It's not named `choose` as it would shadow `IndexedRandom::choose` (which
+                    /// is reachable through deref) depending on whether the feature is enabled.
+                    pub fn choose1<R>(&self, rng: &mut R) -> &$item_ty
+                    where
+                        R: Rng + ?Sized,
+                    {
+                        //UNWRAP_SAFE: len is at least 1
+                        IndexedRandom::choose(self.as_slice(), rng).unwrap()
+                    }
+
+                    /// Chooses one element uniformly at random and returns a mutable reference to it.
+                    ///
+                    /// Like `[T]::choose_mut` but as the vector is never empty no `Option` is returned.
+                    pub fn choose1_mut<R>(&mut self, rng: &mut R) -> &mut $item_ty
+                    where
+                        R: Rng + ?Sized,
+                    {
+                        //UNWRAP_SAFE: len is at least 1
+                        IndexedMutRandom::choose_mut(self.as_mut_slice(), rng).unwrap()
+                    }
+
+                    /// Chooses one element at random, with the probability of each element
+                    /// being proportional to its weight.
+                    ///
+                    /// Like `[T]::choose_weighted` but as the vector is never empty the only
+                    /// possible errors are related to the weights.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If a weight is invalid (e.g. negative) or the sum of all weights is zero
+                    /// or overflows.
+                    pub fn choose_weighted<R, F, B, X>(&self, rng: &mut R, weight: F) -> Result<&$item_ty, WeightError>
+                    where
+                        R: Rng + ?Sized,
+                        F: Fn(&$item_ty) -> B,
+                        B: SampleBorrow<X>,
+                        X: SampleUniform + Weight + PartialOrd<X>,
+                    {
+                        self.as_slice().choose_weighted(rng, weight)
+                    }
+
+                    /// Chooses `amount` distinct elements at random, or all elements if there are less.
+                    ///
+                    /// The order of the returned elements is not specified.
+                    pub fn sample<R>(&self, rng: &mut R, amount: NonZeroUsize) -> crate::Vec1<&$item_ty>
+                    where
+                        R: Rng + ?Sized,
+                    {
+                        let chosen = self.as_slice().choose_multiple(rng, amount.get());
+                        //UNWRAP_SAFE: both len and amount are at least 1
+                        crate::Vec1::try_from_iter(chosen).unwrap()
+                    }
+
+                    /// Shuffles the elements in place.
+                    pub fn shuffle<R>(&mut self, rng: &mut R)
+                    where
+                        R: Rng + ?Sized,
+                    {
+                        self.0.shuffle(rng)
+                    }
+
+                    /// Returns the vector with its elements shuffled.
+                    pub fn into_shuffled<R>(mut self, rng: &mut R) -> Self
+                    where
+                        R: Rng + ?Sized,
+                    {
+                        self.0.shuffle(rng);
+                        self
+                    }
+                }
+            };
+
+            #[cfg(feature = "rayon")]
+            const _: () = {
+                use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+                impl<$t> IntoParallelIterator for $name<$t>
+                where
+                    $item_ty: Send,
+                    $($tb : $trait,)?
+                {
+                    type Item = $item_ty;
+                    type Iter = rayon::vec::IntoIter<$item_ty>;
+
+                    fn into_par_iter(self) -> Self::Iter {
+                        self.into_vec().into_par_iter()
+                    }
+                }
+
+                impl<'a, $t> IntoParallelIterator for &'a $name<$t>
+                where
+                    $item_ty: Sync + 'a,
+                    $($tb : $trait,)?
+                {
+                    type Item = &'a $item_ty;
+                    type Iter = rayon::slice::Iter<'a, $item_ty>;
+
+                    fn into_par_iter(self) -> Self::Iter {
+                        self.as_slice().into_par_iter()
+                    }
+                }
+
+                impl<'a, $t> IntoParallelIterator for &'a mut $name<$t>
+                where
+                    $item_ty: Send + 'a,
+                    $($tb : $trait,)?
+                {
+                    type Item = &'a mut $item_ty;
+                    type Iter = rayon::slice::IterMut<'a, $item_ty>;
+
+                    fn into_par_iter(self) -> Self::Iter {
+                        self.as_mut_slice().into_par_iter()
+                    }
+                }
+
+                impl<$t> $name<$t>
+                where
+                    $($tb : $trait,)?
+                {
+                    /// Like `mapped` but maps the elements in parallel using rayon.
+                    ///
+                    /// The order of the elements is kept.
+                    pub fn par_mapped<F, N>(self, map_fn: F) -> crate::Vec1<N>
+                    where
+                        F: Fn($item_ty) -> N + Sync + Send,
+                        $item_ty: Send,
+                        N: Send,
+                    {
+                        let vec: Vec<N> = self.into_par_iter().map(map_fn).collect();
+                        //UNWRAP_SAFE: len is at least 1
+                        crate::Vec1::try_from_vec(vec).unwrap()
+                    }
+                }
+            };
+
+            #[cfg(feature = "arbitrary")]
+            impl<'a, $t> arbitrary::Arbitrary<'a> for $name<$t>
+            where
+                $item_ty: arbitrary::Arbitrary<'a>,
+                $($tb : $trait,)?
+            {
+                fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                    // the first element is always drawn, so the vector is never empty
+                    let mut vec = $name::new(u.arbitrary()?);
+                    for value in u.arbitrary_iter()? {
+                        vec.push(value?);
+                    }
+                    Ok(vec)
+                }
+
+                fn arbitrary_take_rest(mut u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                    let mut vec = $name::new(u.arbitrary()?);
+                    for value in u.arbitrary_take_rest_iter()? {
+                        vec.push(value?);
+                    }
+                    Ok(vec)
+                }
+
+                fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                    arbitrary::size_hint::and(<$item_ty as arbitrary::Arbitrary<'a>>::size_hint(depth), (0, None))
+                }
+            }
+
+            #[cfg(feature = "speedy")]
+            const _: () = {
+                use speedy::{Context, Readable, Reader, Writable, Writer};
+
+                impl<$t, C> Writable<C> for $name<$t>
+                where
+                    C: Context,
+                    $item_ty: Writable<C>,
+                    $($tb : $trait,)?
+                {
+                    #[inline]
+                    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+                        self.as_slice().write_to(writer)
+                    }
+
+                    #[inline]
+                    fn bytes_needed(&self) -> Result<usize, C::Error> {
+                        Writable::<C>::bytes_needed(self.as_slice())
+                    }
+                }
+
+                impl<'a, $t, C> Readable<'a, C> for $name<$t>
+                where
+                    C: Context,
+                    $item_ty: Readable<'a, C>,
+                    $($tb : $trait,)?
+                {
+                    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+                        let vec = <Vec<$item_ty> as Readable<'a, C>>::read_from(reader)?;
+                        $name::try_from_vec(vec).map_err(|err| speedy::Error::custom(err).into())
+                    }
+
+                    #[inline]
+                    fn minimum_bytes_needed() -> usize {
+                        <Vec<$item_ty> as Readable<'a, C>>::minimum_bytes_needed()
+                    }
+                }
+            };
+
+            #[cfg(feature = "validator")]
+            impl<$t> validator::ValidateLength<u64> for $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn length(&self) -> Option<u64> {
+                    Some(self.len() as u64)
+                }
+            }
+
+            #[cfg(feature = "garde")]
+            impl<$t> garde::rules::length::simple::HasSimpleLength for $name<$t>
+            where
+                $($tb : $trait,)?
+            {
+                fn length(&self) -> usize {
+                    self.len()
+                }
+            }
+
+            #[cfg(feature = "zeroize")]
+            const _: () = {
+                use core::mem::MaybeUninit;
+                use zeroize::{Zeroize, ZeroizeOnDrop};
+
+                impl<$t> Zeroize for $name<$t>
+                where
+                    $item_ty: Zeroize,
+                    $($tb : $trait,)?
+                {
+                    /// "Best effort" zeroization, like the one for `Vec`.
+                    ///
+                    /// As the vector can't be empty this zeroizes all elements and then
+                    /// truncates the vector to its (zeroized) first element instead of
+                    /// clearing it. The spare capacity is zeroized, too, but previous
+                    /// reallocations might have left copies of the values on the heap.
+                    fn zeroize(&mut self) {
+                        self.0.iter_mut().zeroize();
+                        self.0.truncate(1);
+                        let len = self.0.len();
+                        let spare = self.0.capacity() - len;
+                        // SAFETY: the memory between len and capacity belongs to the vector
+                        //         and `MaybeUninit<T>` has the same layout as `T`
+                        let spare = unsafe {
+                            core::slice::from_raw_parts_mut(
+                                self.0.as_mut_ptr().add(len).cast::<MaybeUninit<$item_ty>>(),
+                                spare,
+                            )
+                        };
+                        spare.zeroize();
+                    }
+                }
+
+                impl<$t> ZeroizeOnDrop for $name<$t>
+                where
+                    $item_ty: ZeroizeOnDrop,
+                    $($tb : $trait,)?
+                {}
+            };
+
             //Note: We can not (simply) have if feature serde and feature smallvec enable
             //      dependency smallvec/serde, but we can mirror the serde implementation.
             #[cfg(feature = "serde")]
             const _: () = {
                 use core::marker::PhantomData;
-                use serde::{
+                use ::serde::{
                     de::{SeqAccess,Deserialize, Visitor, Deserializer, Error as _},
                     ser::{Serialize, Serializer, SerializeSeq}
                 };
@@ -999,16 +2436,16 @@ macro_rules! shared_impl {
                     $($tb : $trait,)?
                 {
                     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-                        deserializer.deserialize_seq(SmallVec1Visitor {
+                        deserializer.deserialize_seq(SeqVisitor {
                             _type_carry: PhantomData,
                         })
                     }
                 }
-                struct SmallVec1Visitor<$t> {
+                struct SeqVisitor<$t> {
                     _type_carry: PhantomData<$t>,
                 }
 
-                impl<'de, $t> Visitor<'de> for SmallVec1Visitor<$t>
+                impl<'de, $t> Visitor<'de> for SeqVisitor<$t>
                 where
                     $item_ty: Deserialize<'de>,
                     $($tb : $trait,)?
@@ -1023,10 +2460,12 @@ macro_rules! shared_impl {
                     where
                         B: SeqAccess<'de>,
                     {
-                        let len = seq.size_hint().unwrap_or(0);
-                        let mut vec = $wrapped::new();
-                        //FIXME use try_reserve
-                        vec.reserve(len);
+                        // The size hint comes from untrusted input, so like serde's own
+                        // `Vec` impl don't preallocate more than 1MiB based on it.
+                        const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+                        let max_len = MAX_PREALLOC_BYTES / core::cmp::max(core::mem::size_of::<$item_ty>(), 1);
+                        let len = core::cmp::min(seq.size_hint().unwrap_or(0), max_len);
+                        let mut vec = $wrapped::with_capacity(len);
 
                         while let Some(value) = seq.next_element()? {
                             vec.push(value);
@@ -1042,6 +2481,9 @@ macro_rules! shared_impl {
 
 #[cfg(test)]
 mod tests {
+    // Older tests which are written in a style some newer clippy lints dislike.
+    #![allow(clippy::into_iter_on_ref)]
+
     use core::ops::{Bound, RangeBounds};
 
     #[derive(Debug)]
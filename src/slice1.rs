@@ -0,0 +1,451 @@
+//! A non-empty slice type (`Slice1`), the borrowed counterpart of `Vec1`.
+//!
+//! `Slice1<T>` is a unsized wrapper around `[T]` which guarantees to have at
+//! least 1 element. Like `[T]` it's always used behind a reference (or box).
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::Slice1;
+//!
+//! fn largest(values: &Slice1<u32>) -> u32 {
+//!     // no `Option` to handle
+//!     values.iter().copied().fold(*values.first(), u32::max)
+//! }
+//!
+//! let values = [3, 7, 1];
+//! let slice1 = Slice1::try_from_slice(&values).unwrap();
+//! assert_eq!(largest(slice1), 7);
+//! assert!(Slice1::<u32>::try_from_slice(&[]).is_err());
+//! ```
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+};
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{Iterator1, Size0Error, Vec1};
+
+/// A slice with at least 1 element.
+///
+/// Derefs to `[T]`, but methods like `first` and `last` are shadowed
+/// by methods which don't return a `Option`.
+///
+/// Like for `Vec1` the non-empty guarantee is a logic invariant, not a safety
+/// invariant: safe code can break it (e.g. by leaking the `Drain` returned by
+/// `Vec1::drain`), in which case methods like `first` panic. So unsafe code
+/// must not rely on a `Slice1` being non-empty.
+#[repr(transparent)]
+pub struct Slice1<T>([T]);
+
+impl<T> Slice1<T> {
+    /// Returns a `&Slice1` if the slice is not empty.
+    ///
+    /// # Errors
+    ///
+    /// If the slice is empty.
+    pub fn try_from_slice(slice: &[T]) -> Result<&Self, Size0Error> {
+        if slice.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: we just checked the slice is not empty
+            Ok(unsafe { Self::from_slice_unchecked(slice) })
+        }
+    }
+
+    /// Returns a `&mut Slice1` if the slice is not empty.
+    ///
+    /// # Errors
+    ///
+    /// If the slice is empty.
+    pub fn try_from_mut_slice(slice: &mut [T]) -> Result<&mut Self, Size0Error> {
+        if slice.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: we just checked the slice is not empty
+            Ok(unsafe { Self::from_mut_slice_unchecked(slice) })
+        }
+    }
+
+    /// Creates a `&Slice1` without checking that the slice is not empty.
+    ///
+    /// # Safety
+    ///
+    /// This is `unsafe` because of the layout cast, which is always valid. The
+    /// slice should not be empty, but as non-emptiness is only a logic invariant
+    /// passing an empty slice can only cause panics (e.g. in `first`).
+    pub unsafe fn from_slice_unchecked(slice: &[T]) -> &Self {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: `Slice1<T>` is `repr(transparent)` over `[T]`
+        unsafe { &*(slice as *const [T] as *const Self) }
+    }
+
+    /// Creates a `&mut Slice1` without checking that the slice is not empty.
+    ///
+    /// # Safety
+    ///
+    /// Like [`Slice1::from_slice_unchecked()`] this is only `unsafe` because of
+    /// the layout cast, passing an empty slice can only cause panics.
+    pub unsafe fn from_mut_slice_unchecked(slice: &mut [T]) -> &mut Self {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: `Slice1<T>` is `repr(transparent)` over `[T]`
+        unsafe { &mut *(slice as *mut [T] as *mut Self) }
+    }
+
+    /// Returns a `Box<Slice1>` if the boxed slice is not empty.
+    ///
+    /// # Errors
+    ///
+    /// If the boxed slice is empty.
+    pub fn try_from_boxed_slice(slice: Box<[T]>) -> Result<Box<Self>, Size0Error> {
+        if slice.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: we just checked the slice is not empty
+            Ok(unsafe { Self::from_boxed_slice_unchecked(slice) })
+        }
+    }
+
+    /// Creates a `Box<Slice1>` without checking that the boxed slice is not empty.
+    ///
+    /// # Safety
+    ///
+    /// Like [`Slice1::from_slice_unchecked()`] this is only `unsafe` because of
+    /// the layout cast, passing an empty boxed slice can only cause panics.
+    pub unsafe fn from_boxed_slice_unchecked(slice: Box<[T]>) -> Box<Self> {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: `Slice1<T>` is `repr(transparent)` over `[T]`
+        unsafe { Box::from_raw(Box::into_raw(slice) as *mut Self) }
+    }
+
+    /// Creates a `Slice1` of length 1 from a reference to a single element.
+    pub fn from_ref(element: &T) -> &Self {
+        // SAFETY: the slice has a length of 1
+        unsafe { Self::from_slice_unchecked(core::slice::from_ref(element)) }
+    }
+
+    /// Creates a mutable `Slice1` of length 1 from a mutable reference to a single element.
+    pub fn from_mut(element: &mut T) -> &mut Self {
+        // SAFETY: the slice has a length of 1
+        unsafe { Self::from_mut_slice_unchecked(core::slice::from_mut(element)) }
+    }
+
+    /// Returns the underlying slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the underlying slice mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns a reference to the first element.
+    pub fn first(&self) -> &T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.first().unwrap()
+    }
+
+    /// Returns a mutable reference to the first element.
+    pub fn first_mut(&mut self) -> &mut T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.first_mut().unwrap()
+    }
+
+    /// Returns a reference to the last element.
+    pub fn last(&self) -> &T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.last().unwrap()
+    }
+
+    /// Returns a mutable reference to the last element.
+    pub fn last_mut(&mut self) -> &mut T {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.last_mut().unwrap()
+    }
+
+    /// Returns the first element and the (possibly empty) rest of the slice.
+    pub fn first_and_rest(&self) -> (&T, &[T]) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.split_first().unwrap()
+    }
+
+    /// Returns all but the last element and the last element.
+    pub fn rest_and_last(&self) -> (&[T], &T) {
+        //UNWRAP_SAFE: len is at least 1
+        let (last, rest) = self.0.split_last().unwrap();
+        (rest, last)
+    }
+
+    /// Returns a non-empty iterator over references to the elements.
+    pub fn iter1(&self) -> Iterator1<core::slice::Iter<'_, T>> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.0.iter()) }
+    }
+
+    /// Returns a non-empty iterator over mutable references to the elements.
+    pub fn iter1_mut(&mut self) -> Iterator1<core::slice::IterMut<'_, T>> {
+        // SAFETY: len is at least 1
+        unsafe { Iterator1::new_unchecked(self.0.iter_mut()) }
+    }
+
+    /// Copies the slice into a new `Vec1`.
+    pub fn to_vec1(&self) -> Vec1<T>
+    where
+        T: Clone,
+    {
+        //UNWRAP_SAFE: len is at least 1
+        Vec1::try_from_vec(self.0.to_vec()).unwrap()
+    }
+
+    /// Turns the boxed slice into a boxed `[T]`.
+    pub fn into_boxed_slice(self: Box<Self>) -> Box<[T]> {
+        // SAFETY: `Slice1<T>` is `repr(transparent)` over `[T]`
+        unsafe { Box::from_raw(Box::into_raw(self) as *mut [T]) }
+    }
+
+    /// Turns the boxed slice into a `Vec1` without reallocating.
+    pub fn into_vec1(self: Box<Self>) -> Vec1<T> {
+        //UNWRAP_SAFE: len is at least 1
+        Vec1::try_from_vec(self.into_boxed_slice().into_vec()).unwrap()
+    }
+
+    /// Copies the slice into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.0.to_vec()
+    }
+}
+
+impl<T> Deref for Slice1<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Slice1<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<[T]> for Slice1<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> AsMut<[T]> for Slice1<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<'a, T> TryFrom<&'a [T]> for &'a Slice1<T> {
+    type Error = Size0Error;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Size0Error> {
+        Slice1::try_from_slice(slice)
+    }
+}
+
+impl<'a, T> TryFrom<&'a mut [T]> for &'a mut Slice1<T> {
+    type Error = Size0Error;
+
+    fn try_from(slice: &'a mut [T]) -> Result<Self, Size0Error> {
+        Slice1::try_from_mut_slice(slice)
+    }
+}
+
+impl<T> TryFrom<Box<[T]>> for Box<Slice1<T>> {
+    type Error = Size0Error;
+
+    fn try_from(slice: Box<[T]>) -> Result<Self, Size0Error> {
+        Slice1::try_from_boxed_slice(slice)
+    }
+}
+
+impl<T> From<Box<Slice1<T>>> for Box<[T]> {
+    fn from(slice: Box<Slice1<T>>) -> Self {
+        slice.into_boxed_slice()
+    }
+}
+
+impl<T> From<Box<Slice1<T>>> for Vec1<T> {
+    fn from(slice: Box<Slice1<T>>) -> Self {
+        slice.into_vec1()
+    }
+}
+
+impl<T> From<Vec1<T>> for Box<Slice1<T>> {
+    fn from(vec: Vec1<T>) -> Self {
+        vec.into_boxed_slice1()
+    }
+}
+
+impl<T> Clone for Box<Slice1<T>>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        self.to_vec1().into_boxed_slice1()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Slice1<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Slice1<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T> fmt::Debug for Slice1<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<A, B> PartialEq<Slice1<B>> for Slice1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &Slice1<B>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<A, B> PartialEq<[B]> for Slice1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<A, B, const N: usize> PartialEq<[B; N]> for Slice1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<T> Eq for Slice1<T> where T: Eq {}
+
+impl<T> PartialOrd for Slice1<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T> Ord for Slice1<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> Hash for Slice1<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn try_from_slice() {
+        let slice1 = Slice1::try_from_slice(&[1u8, 2][..]).unwrap();
+        assert_eq!(slice1, &[1u8, 2]);
+        assert_eq!(Slice1::<u8>::try_from_slice(&[]), Err(Size0Error));
+        let res: Result<&Slice1<u8>, _> = (&[][..]).try_into();
+        assert_eq!(res, Err(Size0Error));
+    }
+
+    #[test]
+    fn try_from_mut_slice() {
+        let mut data = [1u8, 2];
+        let slice1 = Slice1::try_from_mut_slice(&mut data).unwrap();
+        *slice1.last_mut() = 3;
+        *slice1.first_mut() = 0;
+        assert_eq!(data, [0, 3]);
+        assert!(Slice1::<u8>::try_from_mut_slice(&mut []).is_err());
+    }
+
+    #[test]
+    fn accessors() {
+        let slice1 = Slice1::try_from_slice(&[1u8, 2, 3]).unwrap();
+        assert_eq!(slice1.first(), &1);
+        assert_eq!(slice1.last(), &3);
+        assert_eq!(slice1.len_nonzero().get(), 3);
+        assert_eq!(slice1.first_and_rest(), (&1, &[2u8, 3][..]));
+        assert_eq!(slice1.rest_and_last(), (&[1u8, 2][..], &3));
+        assert_eq!(slice1.to_vec1(), vec1![1u8, 2, 3]);
+        assert_eq!(slice1.iter().sum::<u8>(), 6);
+        assert_eq!(slice1.iter1().max(), &3);
+    }
+
+    #[test]
+    fn boxed_slice() {
+        let boxed: Box<[u8]> = Box::from([1u8, 2]);
+        let boxed1 = Box::<Slice1<u8>>::try_from(boxed).unwrap();
+        assert_eq!(boxed1.last(), &2);
+        assert_eq!(boxed1.clone().into_vec1(), vec1![1u8, 2]);
+        assert_eq!(Box::<[u8]>::from(boxed1), Box::from([1u8, 2]));
+        assert_eq!(
+            Slice1::try_from_boxed_slice(Box::<[u8]>::from([])),
+            Err(Size0Error)
+        );
+        let boxed1 = vec1![3u8].into_boxed_slice1();
+        assert_eq!(Vec1::from(boxed1), vec1![3u8]);
+    }
+
+    #[test]
+    fn from_ref() {
+        let mut value = 3u8;
+        assert_eq!(Slice1::from_ref(&value), &[3u8]);
+        *Slice1::from_mut(&mut value).first_mut() = 4;
+        assert_eq!(value, 4);
+    }
+}
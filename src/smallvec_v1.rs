@@ -16,14 +16,51 @@
 //! use vec1::smallvec_v1::{smallvec1, SmallVec1};
 //! let v: SmallVec1<[u8; 4]> = smallvec1![1u8, 2];
 //! assert_eq!(&*v, &*vec![1u8,2]);
+//!
+//! // Like `smallvec!` the `[elem; n]` form is supported, too.
+//! let v: SmallVec1<[u8; 4]> = smallvec1![0u8; 3];
+//! assert_eq!(&*v, &[0, 0, 0]);
 //! ```
+//!
+//! # Const Construction
+//!
+//! `smallvec1_inline!` (and [`SmallVec1::from_array_const()`]) create a `SmallVec1`
+//! with a exactly fitting inline buffer in a `const` context, so they can be used
+//! in `const` items (and as initializer of `static` items if the element type allows it).
+//!
+//! ```rust
+//! use vec1::smallvec_v1::{smallvec1_inline, SmallVec1};
+//!
+//! const TABLE: SmallVec1<[u16; 3]> = smallvec1_inline![10, 20, 30];
+//! static TABLE2: SmallVec1<[u16; 2]> = SmallVec1::from_array_const([1, 2]);
+//!
+//! assert_eq!(TABLE.first(), &10);
+//! assert_eq!(TABLE2.last(), &2);
+//! ```
+//!
+//! Using a literal empty array fails at compile time:
+//!
+//! ```compile_fail
+//! use vec1::smallvec_v1::SmallVec1;
+//!
+//! const EMPTY: SmallVec1<[u16; 0]> = SmallVec1::from_array_const([]);
+//! let _ = EMPTY.len();
+//! ```
+
+use crate::{Size0Error, Vec1};
 
-use crate::Size0Error;
+use core::num::NonZeroUsize;
 
 #[cfg(feature = "smallvec-v1-write")]
 use std::io;
 
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
 use alloc::boxed::Box;
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::rc::Rc;
+use alloc::string::String;
 use alloc::vec::Vec;
 use smallvec::*;
 use smallvec_v1_ as smallvec;
@@ -39,6 +76,13 @@ macro_rules! __smallvec1_macro_v1 {
     () => (
         compile_error!("SmallVec1 needs at least 1 element")
     );
+    ($elem:expr; 0) => (
+        compile_error!("SmallVec1 needs at least 1 element")
+    );
+    ($elem:expr; $n:expr) => (
+        $crate::smallvec_v1::SmallVec1::try_from_elem($elem, $n)
+            .expect("smallvec1![elem; n] requires n >= 1")
+    );
     ($first:expr $(, $item:expr)* , ) => (
         $crate::smallvec_v1::smallvec1!($first $(, $item)*)
     );
@@ -76,18 +120,25 @@ shared_impl! {
     /// with the same function signature.
     ///
     /// Methods of `SmallVec` which could reduce the length to 0
-    /// are implemented with a `try_` prefix returning a `Result`.
-    /// (e.g. `try_pop(&self)`, `try_truncate()`, etc.).
+    /// are implemented with the same name but return a `Result`
+    /// (e.g. `pop(&mut self)`, `truncate()`, etc.).
+    ///
+    /// Most methods are shared with `Vec1`, so both types stay in sync.
     ///
     /// Methods with returned `Option<T>` with `None` if the length was 0
     /// (and do not reduce the length) now return T. (e.g. `first`,
     /// `last`, `first_mut`, etc.).
     ///
+    /// Unlike `Vec1`, `SmallVec1` does not implement `Extend<&T>` (neither does `SmallVec`),
+    /// as it would conflict with `Extend<A::Item>`. Use `extend(iter.copied())` or
+    /// `extend_from_slice()` instead.
+    ///
     /// All stable traits and methods implemented on `SmallVec<T>` _should_ also
     /// be implemented on `SmallVec1<T>` (except if they make no sense to implement
     /// due to the len 1 guarantee). Be aware implementations may lack behind a bit,
     /// fell free to open a issue/make a PR, but please search closed and open
     /// issues for duplicates first.
+    #[repr(transparent)]
     pub struct SmallVec1<A>(SmallVec<A>);
 }
 
@@ -113,6 +164,54 @@ where
         }
     }
 
+    /// Creates a `SmallVec1` from a `SmallVec` without checking that it's not empty.
+    ///
+    /// # Safety
+    ///
+    /// The smallvec must not be empty. The length >= 1 constraint is a logic
+    /// invariant, breaking it can only cause panics (e.g. in `first`), so unsafe
+    /// code must not rely on it.
+    pub unsafe fn from_smallvec_unchecked(wrapped: SmallVec<A>) -> Self {
+        debug_assert!(!wrapped.is_empty());
+        Self(wrapped)
+    }
+
+    /// Tries to turn a reference to a `SmallVec` into a reference to a `SmallVec1`.
+    ///
+    /// This doesn't copy anything, it only checks that the `SmallVec` is not empty.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the `SmallVec` is empty.
+    pub fn from_smallvec_ref(wrapped: &SmallVec<A>) -> Result<&Self, Size0Error> {
+        if wrapped.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: `SmallVec1` is `repr(transparent)` over `SmallVec` and we checked
+            //         that the length invariant holds.
+            Ok(unsafe { &*(wrapped as *const SmallVec<A> as *const Self) })
+        }
+    }
+
+    /// Tries to turn a mutable reference to a `SmallVec` into a mutable reference to a `SmallVec1`.
+    ///
+    /// This doesn't copy anything, it only checks that the `SmallVec` is not empty. As
+    /// all methods of `SmallVec1` keep the length >= 1 the `SmallVec` will still
+    /// be non-empty once the returned reference is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the `SmallVec` is empty.
+    pub fn from_smallvec_mut(wrapped: &mut SmallVec<A>) -> Result<&mut Self, Size0Error> {
+        if wrapped.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: `SmallVec1` is `repr(transparent)` over `SmallVec` and we checked
+            //         that the length invariant holds.
+            Ok(unsafe { &mut *(wrapped as *mut SmallVec<A> as *mut Self) })
+        }
+    }
+
     /// See [`SmallVec::from_buf()`] but fails if the `buf` is empty.
     pub fn try_from_buf(buf: A) -> Result<Self, Size0Error> {
         Self::try_from_smallvec(SmallVec::from_buf(buf))
@@ -129,17 +228,17 @@ where
         Self::try_from_smallvec(SmallVec::from_buf_and_len(buf, len))
     }
 
-    /// Converts this instance into the underlying [`$wrapped<$t>`] instance.
+    /// Converts this instance into the underlying [`SmallVec<A>`] instance.
     pub fn into_smallvec(self) -> SmallVec<A> {
         self.0
     }
 
-    /// Return a reference to the underlying `$wrapped`.
+    /// Return a reference to the underlying `SmallVec`.
     pub fn as_smallvec(&self) -> &SmallVec<A> {
         &self.0
     }
 
-    /// Converts this instance into a [`Vec<$item_ty>`] instance.
+    /// Converts this instance into a [`Vec<A::Item>`] instance.
     pub fn into_vec(self) -> Vec<A::Item> {
         self.0.into_vec()
     }
@@ -159,6 +258,178 @@ where
     pub fn insert_many<I: IntoIterator<Item = A::Item>>(&mut self, index: usize, iterable: I) {
         self.0.insert_many(index, iterable)
     }
+
+    /// Like [`SmallVec1::insert_many()`] but panic safe and without panicking on out of bounds indices.
+    ///
+    /// All items are first collected into a temporary `SmallVec<A>` before any modification
+    /// is done to `self`. As such if the iterator panics `self` is left unchanged, no elements
+    /// are leaked and the non-empty invariant can not be affected.
+    ///
+    /// # Errors
+    ///
+    /// If `index > len` the (not yet iterated) `iterable` is returned as error.
+    pub fn try_insert_many<I>(&mut self, index: usize, iterable: I) -> Result<(), I>
+    where
+        I: IntoIterator<Item = A::Item>,
+    {
+        if index > self.len() {
+            return Err(iterable);
+        }
+        let items: SmallVec<A> = iterable.into_iter().collect();
+        self.0.insert_many(index, items);
+        Ok(())
+    }
+
+    /// Create a new `SmallVec1` by consuming `self` and mapping each element.
+    ///
+    /// This is useful as it keeps the knowledge that the length is >= 1,
+    /// even through the old `SmallVec1` is consumed and turned into an iterator.
+    ///
+    /// The buffer type `B` of the returned `SmallVec1` can be chosen freely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::smallvec_v1::{smallvec1, SmallVec1};
+    /// let data: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+    ///
+    /// let data: SmallVec1<[u16; 8]> = data.mapped(|x| x as u16 * 2);
+    /// assert_eq!(&*data, &[2, 4, 6]);
+    /// ```
+    pub fn mapped<F, B>(self, map_fn: F) -> SmallVec1<B>
+    where
+        F: FnMut(A::Item) -> B::Item,
+        B: Array,
+    {
+        SmallVec1(self.into_iter().map(map_fn).collect())
+    }
+
+    /// Create a new `SmallVec1` by mapping references to the elements of `self`.
+    ///
+    /// The benefit to this compared to `Iterator::map` is that it's known
+    /// that the length will still be at least 1 when creating the new `SmallVec1`.
+    pub fn mapped_ref<'a, F, B>(&'a self, map_fn: F) -> SmallVec1<B>
+    where
+        F: FnMut(&'a A::Item) -> B::Item,
+        B: Array,
+    {
+        SmallVec1(self.iter().map(map_fn).collect())
+    }
+
+    /// Create a new `SmallVec1` by mapping mutable references to the elements of `self`.
+    ///
+    /// The benefit to this compared to `Iterator::map` is that it's known
+    /// that the length will still be at least 1 when creating the new `SmallVec1`.
+    pub fn mapped_mut<'a, F, B>(&'a mut self, map_fn: F) -> SmallVec1<B>
+    where
+        F: FnMut(&'a mut A::Item) -> B::Item,
+        B: Array,
+    {
+        SmallVec1(self.iter_mut().map(map_fn).collect())
+    }
+
+    /// Create a new `SmallVec1` by consuming `self` and mapping each element
+    /// to a `Result`.
+    ///
+    /// As this method consumes self, returning an error means that this
+    /// vec is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Once any call to `map_fn` returns a error that error is directly
+    /// returned by this method.
+    pub fn try_mapped<F, B, E>(self, map_fn: F) -> Result<SmallVec1<B>, E>
+    where
+        F: FnMut(A::Item) -> Result<B::Item, E>,
+        B: Array,
+    {
+        let mut map_fn = map_fn;
+        let mut out = SmallVec::with_capacity(self.len());
+        for element in self {
+            out.push(map_fn(element)?);
+        }
+        Ok(SmallVec1(out))
+    }
+
+    /// Create a new `SmallVec1` by mapping references to the elements of `self`
+    /// to `Result`s.
+    ///
+    /// # Errors
+    ///
+    /// Once any call to `map_fn` returns a error that error is directly
+    /// returned by this method.
+    pub fn try_mapped_ref<'a, F, B, E>(&'a self, map_fn: F) -> Result<SmallVec1<B>, E>
+    where
+        F: FnMut(&'a A::Item) -> Result<B::Item, E>,
+        B: Array,
+    {
+        let mut map_fn = map_fn;
+        let mut out = SmallVec::with_capacity(self.len());
+        for element in self.iter() {
+            out.push(map_fn(element)?);
+        }
+        Ok(SmallVec1(out))
+    }
+
+    /// Create a new `SmallVec1` by mapping mutable references to the elements of
+    /// `self` to `Result`s.
+    ///
+    /// # Errors
+    ///
+    /// Once any call to `map_fn` returns a error that error is directly
+    /// returned by this method.
+    pub fn try_mapped_mut<'a, F, B, E>(&'a mut self, map_fn: F) -> Result<SmallVec1<B>, E>
+    where
+        F: FnMut(&'a mut A::Item) -> Result<B::Item, E>,
+        B: Array,
+    {
+        let mut map_fn = map_fn;
+        let mut out = SmallVec::with_capacity(self.len());
+        for element in self.iter_mut() {
+            out.push(map_fn(element)?);
+        }
+        Ok(SmallVec1(out))
+    }
+
+    /// Splits off the tail starting at `at` into a `SmallVec`, keeping the (never empty) head.
+    ///
+    /// Works like [`Vec1::split_off_rest()`](crate::Vec1::split_off_rest).
+    ///
+    /// # Panics
+    ///
+    /// **If `at` is greater then `len`.**
+    pub fn split_off_rest(&mut self, at: NonZeroUsize) -> SmallVec<A> {
+        let at = at.get();
+        let len = self.len();
+        if at > len {
+            panic!("split_off index (is {}) should be <= len (is {})", at, len);
+        }
+        self.0.drain(at..).collect()
+    }
+
+    /// Splits off the elements starting at `at` into a new `SmallVec1`.
+    ///
+    /// Works like [`Vec1::split_off()`](crate::Vec1::split_off).
+    ///
+    /// # Panics
+    ///
+    /// **If `at` is greater then `len`.**
+    ///
+    /// # Errors
+    ///
+    /// If splitting would result in an empty `SmallVec1` an error is returned, this happens
+    /// if `at` is `0` or `at` is equals to `len`.
+    pub fn split_off(&mut self, at: usize) -> Result<Self, Size0Error> {
+        let len = self.len();
+        if at > len {
+            panic!("split_off index (is {}) should be <= len (is {})", at, len);
+        }
+        if at == 0 || at == len {
+            Err(Size0Error)
+        } else {
+            Ok(Self(self.0.drain(at..).collect()))
+        }
+    }
 }
 
 impl<A> SmallVec1<A>
@@ -166,6 +437,7 @@ where
     A: Array,
     A::Item: Copy,
 {
+    /// See [`SmallVec::from_slice()`] but fails if the `slice` is empty.
     pub fn try_from_slice(slice: &[A::Item]) -> Result<Self, Size0Error> {
         if slice.is_empty() {
             Err(Size0Error)
@@ -174,6 +446,7 @@ where
         }
     }
 
+    /// See [`SmallVec::insert_from_slice()`].
     pub fn insert_from_slice(&mut self, index: usize, slice: &[A::Item]) {
         self.0.insert_from_slice(index, slice)
     }
@@ -184,6 +457,7 @@ where
     A: Array,
     A::Item: Clone,
 {
+    /// See [`SmallVec::from_elem()`] but fails if `len` is 0.
     pub fn try_from_elem(element: A::Item, len: usize) -> Result<Self, Size0Error> {
         if len == 0 {
             Err(Size0Error)
@@ -193,6 +467,25 @@ where
     }
 }
 
+impl<A> SmallVec1<A>
+where
+    A: Array<Item = u8>,
+{
+    /// Works like `&[u8].to_ascii_uppercase()` but returns a `SmallVec1<A>` instead of a `Vec<u8>`
+    pub fn to_ascii_uppercase(&self) -> Self {
+        let mut out = self.clone();
+        out.make_ascii_uppercase();
+        out
+    }
+
+    /// Works like `&[u8].to_ascii_lowercase()` but returns a `SmallVec1<A>` instead of a `Vec<u8>`
+    pub fn to_ascii_lowercase(&self) -> Self {
+        let mut out = self.clone();
+        out.make_ascii_lowercase();
+        out
+    }
+}
+
 impl<T, const N: usize> SmallVec1<[T; N]> {
     /// Creates a new `SmallVec1` from an array.
     ///
@@ -231,6 +524,28 @@ where
     }
 }
 
+impl<A, T> PartialEq<Vec1<T>> for SmallVec1<A>
+where
+    A::Item: PartialEq<T>,
+    A: Array,
+{
+    #[inline]
+    fn eq(&self, other: &Vec1<T>) -> bool {
+        self.as_slice().eq(other.as_slice())
+    }
+}
+
+impl<A, T> PartialEq<SmallVec1<A>> for Vec1<T>
+where
+    T: PartialEq<A::Item>,
+    A: Array,
+{
+    #[inline]
+    fn eq(&self, other: &SmallVec1<A>) -> bool {
+        self.as_slice().eq(other.as_slice())
+    }
+}
+
 ///FIXME(v2.0) use `From` and panic on `N==0` instead.
 impl<T, const N: usize> TryFrom<[T; N]> for SmallVec1<[T; N]> {
     type Error = Size0Error;
@@ -239,6 +554,39 @@ impl<T, const N: usize> TryFrom<[T; N]> for SmallVec1<[T; N]> {
     }
 }
 
+impl<A> TryFrom<&'_ mut [A::Item]> for SmallVec1<A>
+where
+    A: Array,
+    A::Item: Clone,
+{
+    type Error = Size0Error;
+    fn try_from(slice: &mut [A::Item]) -> Result<Self, Size0Error> {
+        Self::try_from(&*slice)
+    }
+}
+
+impl<A, const N: usize> TryFrom<&[A::Item; N]> for SmallVec1<A>
+where
+    A: Array,
+    A::Item: Clone,
+{
+    type Error = Size0Error;
+    fn try_from(array: &[A::Item; N]) -> Result<Self, Size0Error> {
+        Self::try_from(&array[..])
+    }
+}
+
+impl<A, const N: usize> TryFrom<&mut [A::Item; N]> for SmallVec1<A>
+where
+    A: Array,
+    A::Item: Clone,
+{
+    type Error = Size0Error;
+    fn try_from(array: &mut [A::Item; N]) -> Result<Self, Size0Error> {
+        Self::try_from(&array[..])
+    }
+}
+
 impl<T, const N: usize> TryFrom<SmallVec1<[T; N]>> for [T; N] {
     type Error = SmallVec1<[T; N]>;
     fn try_from(vec: SmallVec1<[T; N]>) -> Result<Self, SmallVec1<[T; N]>> {
@@ -277,6 +625,50 @@ where
     }
 }
 
+impl<A> TryFrom<VecDeque<A::Item>> for SmallVec1<A>
+where
+    A: Array,
+{
+    type Error = Size0Error;
+    fn try_from(deque: VecDeque<A::Item>) -> Result<Self, Size0Error> {
+        if deque.is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(Self(deque.into_iter().collect()))
+        }
+    }
+}
+
+impl<A> TryFrom<BinaryHeap<A::Item>> for SmallVec1<A>
+where
+    A: Array,
+{
+    type Error = Size0Error;
+    fn try_from(heap: BinaryHeap<A::Item>) -> Result<Self, Size0Error> {
+        Self::try_from_vec(heap.into_vec())
+    }
+}
+
+impl<A> TryFrom<String> for SmallVec1<A>
+where
+    A: Array<Item = u8>,
+{
+    type Error = Size0Error;
+    fn try_from(string: String) -> Result<Self, Size0Error> {
+        Self::try_from_vec(string.into_bytes())
+    }
+}
+
+impl<A> TryFrom<&'_ str> for SmallVec1<A>
+where
+    A: Array<Item = u8>,
+{
+    type Error = Size0Error;
+    fn try_from(string: &str) -> Result<Self, Size0Error> {
+        Self::try_from_slice(string.as_bytes())
+    }
+}
+
 impl<A> From<SmallVec1<A>> for Box<[A::Item]>
 where
     A: Array,
@@ -286,6 +678,68 @@ where
     }
 }
 
+impl<A> SmallVec1<A>
+where
+    A: Array,
+{
+    /// Converts this instance into a `Vec1`.
+    ///
+    /// If the `SmallVec` already spilled onto the heap this reuses the allocation.
+    pub fn into_vec1(self) -> Vec1<A::Item> {
+        Vec1(self.0.into_vec())
+    }
+}
+
+impl<T> Vec1<T> {
+    /// Converts this instance into a `SmallVec1` with the given buffer type.
+    ///
+    /// If the elements fit into the inline buffer they are moved into it,
+    /// else the allocation of the `Vec` is reused.
+    pub fn into_smallvec1<A>(self) -> SmallVec1<A>
+    where
+        A: Array<Item = T>,
+    {
+        SmallVec1(SmallVec::from_vec(self.0))
+    }
+}
+
+impl<A> From<SmallVec1<A>> for Vec1<A::Item>
+where
+    A: Array,
+{
+    fn from(vec: SmallVec1<A>) -> Self {
+        vec.into_vec1()
+    }
+}
+
+impl<A> From<Vec1<A::Item>> for SmallVec1<A>
+where
+    A: Array,
+{
+    fn from(vec: Vec1<A::Item>) -> Self {
+        vec.into_smallvec1()
+    }
+}
+
+impl<A> From<SmallVec1<A>> for Rc<[A::Item]>
+where
+    A: Array,
+{
+    fn from(vec: SmallVec1<A>) -> Self {
+        vec.into_vec().into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A> From<SmallVec1<A>> for Arc<[A::Item]>
+where
+    A: Array,
+{
+    fn from(vec: SmallVec1<A>) -> Self {
+        vec.into_vec().into()
+    }
+}
+
 #[cfg(feature = "smallvec-v1-write")]
 impl<A> io::Write for SmallVec1<A>
 where
@@ -312,11 +766,117 @@ where
     }
 }
 
+/// Builder which collects elements and turns them into a `SmallVec1` once done.
+///
+/// Like [`Vec1Builder`](crate::Vec1Builder) but backed by a `SmallVec`.
+pub struct SmallVec1Builder<A>(SmallVec<A>)
+where
+    A: Array;
+
+impl<A> SmallVec1Builder<A>
+where
+    A: Array,
+{
+    /// Creates a new, empty builder.
+    pub fn new() -> Self {
+        SmallVec1Builder(SmallVec::new())
+    }
+
+    /// Creates a new, empty builder with space for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SmallVec1Builder(SmallVec::with_capacity(capacity))
+    }
+
+    /// Appends an element to the back.
+    pub fn push(&mut self, value: A::Item) {
+        self.0.push(value)
+    }
+
+    /// Returns the number of elements collected so far.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no element was collected so far.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the elements collected so far as slice.
+    pub fn as_slice(&self) -> &[A::Item] {
+        &self.0
+    }
+
+    /// Turns the builder into a `SmallVec1`.
+    ///
+    /// # Errors
+    ///
+    /// If no element was collected.
+    pub fn finish(self) -> Result<SmallVec1<A>, Size0Error> {
+        SmallVec1::try_from_smallvec(self.0)
+    }
+}
+
+impl<A> Default for SmallVec1Builder<A>
+where
+    A: Array,
+{
+    fn default() -> Self {
+        SmallVec1Builder::new()
+    }
+}
+
+impl<A> Clone for SmallVec1Builder<A>
+where
+    A: Array,
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SmallVec1Builder(self.0.clone())
+    }
+}
+
+impl<A> Extend<A::Item> for SmallVec1Builder<A>
+where
+    A: Array,
+{
+    fn extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<A> FromIterator<A::Item> for SmallVec1Builder<A>
+where
+    A: Array,
+{
+    fn from_iter<I: IntoIterator<Item = A::Item>>(iter: I) -> Self {
+        SmallVec1Builder(SmallVec::from_iter(iter))
+    }
+}
+
+impl<A> core::fmt::Debug for SmallVec1Builder<A>
+where
+    A: Array,
+    A::Item: core::fmt::Debug,
+{
+    fn fmt(&self, fter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fter.debug_tuple("SmallVec1Builder")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     mod SmallVec1 {
         #![allow(non_snake_case)]
+        // Older tests which are written in a style some newer clippy lints dislike.
+        #![allow(
+            clippy::bool_assert_comparison,
+            clippy::explicit_auto_deref,
+            clippy::useless_conversion
+        )]
         use super::super::*;
         use core::num::NonZeroUsize;
         use std::{
@@ -362,6 +922,20 @@ mod tests {
             assert_eq!(a, b);
         }
 
+        #[test]
+        fn PartialEq_Vec1() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            let b = crate::vec1![1u8, 2];
+            let c = crate::vec1![1u8, 3];
+            assert_eq!(a, b);
+            assert_eq!(b, a);
+            assert_ne!(a, c);
+            assert_ne!(c, a);
+            let d: SmallVec1<[&str; 2]> = smallvec1!["a"];
+            let e = crate::vec1![String::from("a")];
+            assert!(e == d);
+        }
+
         #[test]
         fn Ord() {
             let a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
@@ -389,6 +963,7 @@ mod tests {
             assert_eq!(format!("{:?}", a), "[1, 2]");
         }
 
+        #[cfg(not(feature = "disable-default-impl"))]
         #[test]
         fn Default() {
             let a = SmallVec1::<[u8; 4]>::default();
@@ -478,6 +1053,8 @@ mod tests {
             let mut a: SmallVec1<[u8; 4]> = smallvec1![12, 23];
             a.extend(vec![1u8, 2, 3].into_iter());
             assert_eq!(a.as_slice(), &[12u8, 23, 1, 2, 3] as &[u8]);
+            a.extend([4u8, 5].iter().copied());
+            assert_eq!(a.as_slice(), &[12u8, 23, 1, 2, 3, 4, 5] as &[u8]);
         }
 
         #[test]
@@ -506,6 +1083,15 @@ mod tests {
                 SmallVec1::<[String; 4]>::try_from(&[] as &[String]).unwrap_err();
             }
 
+            #[test]
+            fn mut_slice() {
+                let mut data = ["hy".to_owned()];
+                let a = SmallVec1::<[String; 4]>::try_from(&mut data as &mut [String]).unwrap();
+                assert_eq!(a[0], "hy");
+
+                SmallVec1::<[String; 4]>::try_from(&mut [] as &mut [String]).unwrap_err();
+            }
+
             #[test]
             fn misc() {
                 let _ = SmallVec1::<[u8; 4]>::try_from(vec![1, 2, 3]).unwrap();
@@ -516,6 +1102,56 @@ mod tests {
                 let _ = SmallVec1::<[u8; 0]>::try_from([] as [u8; 0]).unwrap_err();
             }
 
+            #[test]
+            fn array_refs_of_any_size() {
+                let a = SmallVec1::<[u8; 2]>::try_from(&[1u8, 2, 3, 4, 5]).unwrap();
+                assert_eq!(&*a, &[1, 2, 3, 4, 5]);
+                let a = SmallVec1::<[u8; 8]>::try_from(&mut [1u8, 2, 3]).unwrap();
+                assert_eq!(&*a, &[1, 2, 3]);
+                assert!(!a.spilled());
+                SmallVec1::<[u8; 8]>::try_from(&[] as &[u8; 0]).unwrap_err();
+                SmallVec1::<[u8; 8]>::try_from(&mut [] as &mut [u8; 0]).unwrap_err();
+            }
+
+            #[test]
+            fn vec_deque() {
+                let deque: std::collections::VecDeque<u8> = vec![1, 2, 3].into();
+                let a = SmallVec1::<[u8; 4]>::try_from(deque).unwrap();
+                assert_eq!(&*a, &[1, 2, 3]);
+                SmallVec1::<[u8; 4]>::try_from(std::collections::VecDeque::new()).unwrap_err();
+            }
+
+            #[test]
+            fn binary_heap() {
+                let heap: std::collections::BinaryHeap<u8> = vec![1, 2, 3].into();
+                let a = SmallVec1::<[u8; 4]>::try_from(heap).unwrap();
+                assert_eq!(a.len(), 3);
+                SmallVec1::<[u8; 4]>::try_from(std::collections::BinaryHeap::new()).unwrap_err();
+            }
+
+            #[test]
+            fn string() {
+                let a = SmallVec1::<[u8; 4]>::try_from("hy".to_owned()).unwrap();
+                assert_eq!(&*a, b"hy");
+                SmallVec1::<[u8; 4]>::try_from(String::new()).unwrap_err();
+            }
+
+            #[test]
+            fn str() {
+                let a = SmallVec1::<[u8; 4]>::try_from("hy").unwrap();
+                assert_eq!(&*a, b"hy");
+                SmallVec1::<[u8; 4]>::try_from("").unwrap_err();
+            }
+
+            #[test]
+            fn boxed_slice() {
+                let boxed: Box<[u8]> = vec![1, 2].into_boxed_slice();
+                let a = SmallVec1::<[u8; 4]>::try_from(boxed).unwrap();
+                assert_eq!(&*a, &[1, 2]);
+                let boxed: Box<[u8]> = vec![].into_boxed_slice();
+                SmallVec1::<[u8; 4]>::try_from(boxed).unwrap_err();
+            }
+
             #[test]
             fn array_try_from_smallvec1() {
                 let vec: SmallVec1<[u8; 4]> = smallvec1![1, 3, 2, 4];
@@ -562,6 +1198,30 @@ mod tests {
             assert_eq!(a, Err(Size0Error));
         }
 
+        #[test]
+        fn from_smallvec_ref() {
+            let a: SmallVec<[u8; 4]> = smallvec![32, 2, 3];
+            let b = SmallVec1::from_smallvec_ref(&a).unwrap();
+            assert_eq!(b.first(), &32);
+            assert!(core::ptr::eq(b.as_smallvec(), &a));
+
+            let a: SmallVec<[u8; 4]> = smallvec![];
+            assert_eq!(SmallVec1::from_smallvec_ref(&a), Err(Size0Error));
+        }
+
+        #[test]
+        fn from_smallvec_mut() {
+            let mut a: SmallVec<[u8; 4]> = smallvec![32, 2, 3];
+            let b = SmallVec1::from_smallvec_mut(&mut a).unwrap();
+            assert_eq!(b.pop(), Ok(3));
+            assert_eq!(b.pop(), Ok(2));
+            assert_eq!(b.pop(), Err(Size0Error));
+            assert_eq!(&*a, &[32]);
+
+            let mut a: SmallVec<[u8; 4]> = smallvec![];
+            assert!(SmallVec1::from_smallvec_mut(&mut a).is_err());
+        }
+
         #[test]
         fn try_from_buf() {
             let a = SmallVec1::try_from_buf([1u8, 2, 3, 4]);
@@ -622,6 +1282,14 @@ mod tests {
             assert_eq!(s, &[1u8, 3]);
         }
 
+        #[test]
+        fn leak1() {
+            let a: SmallVec1<[u8; 32]> = smallvec1![1u8, 3];
+            let s: &'static mut crate::Slice1<u8> = a.leak1();
+            assert_eq!(s.first(), &1);
+            assert_eq!(s, &[1u8, 3]);
+        }
+
         #[test]
         fn reduce() {
             assert_eq!(smallvec1_inline![1u8, 2, 4, 3].reduce(std::cmp::max), 4);
@@ -657,6 +1325,40 @@ mod tests {
                 let _ = Vec::<u8>::from(vec);
             }
 
+            #[test]
+            fn rc_from_smallvec1() {
+                let vec: SmallVec1<[u8; 4]> = smallvec1![1, 3, 2, 4];
+                let rc = Rc::<[u8]>::from(vec);
+                assert_eq!(&*rc, &[1u8, 3, 2, 4]);
+            }
+
+            #[cfg(feature = "std")]
+            #[test]
+            fn arc_from_smallvec1() {
+                let vec: SmallVec1<[u8; 4]> = smallvec1![1, 3, 2, 4, 5];
+                let arc = std::sync::Arc::<[u8]>::from(vec);
+                assert_eq!(&*arc, &[1u8, 3, 2, 4, 5]);
+            }
+
+            #[test]
+            fn vec1_from_smallvec1() {
+                let vec: SmallVec1<[u8; 4]> = smallvec1![1, 3, 2, 4];
+                let vec1 = crate::Vec1::from(vec.clone());
+                assert_eq!(&*vec1, &*vec);
+                assert_eq!(vec.into_vec1(), vec1);
+            }
+
+            #[test]
+            fn smallvec1_from_vec1() {
+                let vec1 = crate::vec1![1u8, 3];
+                let vec = SmallVec1::<[u8; 4]>::from(vec1.clone());
+                assert_eq!(&*vec, &*vec1);
+                assert!(!vec.spilled());
+                let vec: SmallVec1<[u8; 1]> = vec1.into_smallvec1();
+                assert_eq!(&*vec, &[1, 3]);
+                assert!(vec.spilled());
+            }
+
             #[test]
             fn smallvec_from_smallvec1() {
                 let vec: SmallVec1<[u8; 4]> = smallvec1![1, 3, 2, 4];
@@ -774,6 +1476,15 @@ mod tests {
             assert_eq!(a, b);
         }
 
+        #[test]
+        fn as_slice1() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 3];
+            assert_eq!(a.as_slice1().last(), &3);
+            *a.as_mut_slice1().first_mut() = 10;
+            let b: &crate::Slice1<u8> = a.as_ref();
+            assert_eq!(b, &[10u8, 3]);
+        }
+
         #[test]
         fn inline_size() {
             let a: SmallVec1<[u8; 4]> = smallvec1![1, 3];
@@ -814,6 +1525,196 @@ mod tests {
             assert_eq!(a, c);
         }
 
+        #[test]
+        fn smallvec1_macro_repetition() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![7u8; 3];
+            assert_eq!(&*a, &[7, 7, 7]);
+            catch_unwind(|| {
+                let n = 0;
+                let _: SmallVec1<[u8; 4]> = smallvec1![1u8; n];
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        fn try_from_iter() {
+            let a = SmallVec1::<[u8; 4]>::try_from_iter(1..4).unwrap();
+            assert_eq!(&*a, &[1, 2, 3]);
+            assert!(!a.spilled());
+            assert_eq!(SmallVec1::<[u8; 4]>::try_from_iter(None), Err(Size0Error));
+        }
+
+        #[test]
+        fn collect1() {
+            use crate::IteratorExt;
+
+            let a: SmallVec1<[u8; 4]> = (1..3).collect1().unwrap();
+            assert_eq!(&*a, &[1, 2]);
+            assert_eq!((1..1).collect1::<SmallVec1<[u8; 4]>>(), Err(Size0Error));
+        }
+
+        #[test]
+        fn try_insert_many() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 5];
+            a.try_insert_many(1, vec![2, 3, 4]).unwrap();
+            assert_eq!(&*a, &[1, 2, 3, 4, 5]);
+            a.try_insert_many(5, Some(6)).unwrap();
+            assert_eq!(&*a, &[1, 2, 3, 4, 5, 6]);
+            assert_eq!(a.try_insert_many(7, Some(8)), Err(Some(8)));
+            assert_eq!(&*a, &[1, 2, 3, 4, 5, 6]);
+        }
+
+        #[test]
+        fn try_insert_many_is_panic_safe() {
+            let mut a: SmallVec1<[String; 2]> = smallvec1!["a".to_owned(), "b".to_owned()];
+            let res = catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let iter = (0..4).map(|idx| {
+                    if idx == 2 {
+                        panic!("iterator panic");
+                    }
+                    format!("{}", idx)
+                });
+                let _ = a.try_insert_many(1, iter);
+            }));
+            assert!(res.is_err());
+            assert_eq!(&*a, &["a".to_owned(), "b".to_owned()]);
+        }
+
+        #[test]
+        fn mapped() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let b: SmallVec1<[u16; 2]> = a.mapped(|x| x as u16 * 2);
+            assert_eq!(&*b, &[2, 4, 6]);
+        }
+
+        #[test]
+        fn mapped_ref() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let b: SmallVec1<[&u8; 4]> = a.mapped_ref(|x| x);
+            assert_eq!(&*b, &[&1, &2, &3]);
+        }
+
+        #[test]
+        fn mapped_mut() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let b: SmallVec1<[u8; 4]> = a.mapped_mut(|x| {
+                *x += 1;
+                *x * 2
+            });
+            assert_eq!(&*a, &[2, 3, 4]);
+            assert_eq!(&*b, &[4, 6, 8]);
+        }
+
+        #[test]
+        fn try_mapped() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let b: Result<SmallVec1<[u8; 4]>, u8> = a.clone().try_mapped(Ok);
+            assert_eq!(b, Ok(a.clone()));
+            let c: Result<SmallVec1<[u8; 4]>, u8> =
+                a.try_mapped(|x| if x == 2 { Err(x) } else { Ok(x) });
+            assert_eq!(c, Err(2));
+        }
+
+        #[test]
+        fn try_mapped_ref() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let b: Result<SmallVec1<[&u8; 4]>, ()> = a.try_mapped_ref(Ok);
+            assert_eq!(&*b.unwrap(), &[&1, &2, &3]);
+            let c: Result<SmallVec1<[u8; 4]>, u8> = a.try_mapped_ref(|x| Err(*x));
+            assert_eq!(c, Err(1));
+        }
+
+        #[test]
+        fn try_mapped_mut() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let b: Result<SmallVec1<[u8; 4]>, ()> = a.try_mapped_mut(|x| {
+                *x += 1;
+                Ok(*x)
+            });
+            assert_eq!(&*b.unwrap(), &[2, 3, 4]);
+            let c: Result<SmallVec1<[u8; 4]>, u8> = a.try_mapped_mut(|x| Err(*x));
+            assert_eq!(c, Err(2));
+        }
+
+        #[test]
+        fn to_ascii_uppercase() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![b'a', b'B', b'1'];
+            let b = a.to_ascii_uppercase();
+            assert_eq!(&*b, b"AB1");
+            assert_eq!(&*a, b"aB1");
+        }
+
+        #[test]
+        fn to_ascii_lowercase() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![b'a', b'B', b'1'];
+            let b = a.to_ascii_lowercase();
+            assert_eq!(&*b, b"ab1");
+            assert_eq!(&*a, b"aB1");
+        }
+
+        #[test]
+        fn split_off() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 3, 5, 7];
+            assert_eq!(a.split_off(0), Err(Size0Error));
+            assert_eq!(a.split_off(4), Err(Size0Error));
+            let b = a.split_off(1).unwrap();
+            assert_eq!(&*a, &[1]);
+            assert_eq!(&*b, &[3, 5, 7]);
+        }
+
+        #[test]
+        fn first_and_last_chunk() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.first_chunk::<2>(), Some(&[1u8, 2]));
+            assert_eq!(a.last_chunk::<2>(), Some(&[2u8, 3]));
+            assert_eq!(a.last_chunk::<4>(), None);
+            assert_eq!(a.first_as_array(), &[1]);
+            assert_eq!(a.last_as_array(), &[3]);
+        }
+
+        #[test]
+        fn first_and_rest() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.first_and_rest(), (&1, &[2u8, 3] as &[u8]));
+            let (first, rest) = a.first_and_rest_mut();
+            *first = 10;
+            rest[1] = 30;
+            assert_eq!(&*a, &[10, 2, 30]);
+        }
+
+        #[test]
+        fn rest_and_last() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.rest_and_last(), (&[1u8, 2] as &[u8], &3));
+            let (rest, last) = a.rest_and_last_mut();
+            *last = 30;
+            rest[0] = 10;
+            assert_eq!(&*a, &[10, 2, 30]);
+        }
+
+        #[test]
+        fn split_off_rest() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 3, 5, 7];
+            let b = a.split_off_rest(NonZeroUsize::new(1).unwrap());
+            assert_eq!(&*a, &[1]);
+            assert_eq!(&*b, &[3, 5, 7]);
+            let b = a.split_off_rest(NonZeroUsize::new(1).unwrap());
+            assert!(b.is_empty());
+
+            catch_unwind(|| {
+                let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 3];
+                let _ = a.split_off_rest(NonZeroUsize::new(3).unwrap());
+            })
+            .unwrap_err();
+        }
+
+        #[test]
+        #[should_panic]
+        fn split_off_panics_if_out_of_bounds() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 3];
+            let _ = a.split_off(3);
+        }
+
         #[test]
         fn grow() {
             let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 3];
@@ -835,6 +1736,23 @@ mod tests {
             assert_eq!(a.swap_remove(0), Err(Size0Error));
         }
 
+        #[test]
+        fn pop_many() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.pop_many(3), Err(Size0Error));
+            assert_eq!(a.pop_many(2), Ok(std::vec![2u8, 3]));
+            assert_eq!(a.as_slice(), &[1u8] as &[u8]);
+        }
+
+        #[test]
+        fn swap_remove_front() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.swap_remove_front(), Ok(1));
+            assert_eq!(a.as_slice(), &[3u8, 2] as &[u8]);
+            assert_eq!(a.swap_remove_front(), Ok(3));
+            assert_eq!(a.swap_remove_front(), Err(Size0Error));
+        }
+
         #[test]
         fn try_swap_remove() {
             #![allow(deprecated)]
@@ -895,6 +1813,138 @@ mod tests {
             assert_eq!(a.as_slice(), &[(1u8, 2u8), (4, 4), (5, 4)] as &[(u8, u8)]);
         }
 
+        #[test]
+        fn edit() {
+            let mut a: SmallVec1<[u8; 2]> = smallvec1![1, 2];
+            a.edit(|vec| vec.extend([3, 4])).unwrap();
+            assert_eq!(a.as_slice(), &[1u8, 2, 3, 4] as &[u8]);
+            assert_eq!(a.edit(|vec| vec.clear()), Err(Size0Error));
+            assert_eq!(a.as_slice(), &[0u8] as &[u8]);
+        }
+
+        #[test]
+        fn unchecked_constructors() {
+            let a = unsafe { SmallVec1::<[u8; 2]>::from_smallvec_unchecked(smallvec![1, 2]) };
+            assert_eq!(a.as_slice(), &[1u8, 2] as &[u8]);
+            let a = unsafe { SmallVec1::<[u8; 2]>::from_vec_unchecked(std::vec![3]) };
+            assert_eq!(a.as_slice(), &[3u8] as &[u8]);
+        }
+
+        #[test]
+        fn sort_and_dedup() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![3, 1, 3, 2, 1];
+            assert_eq!(a.sort_and_dedup().as_slice(), &[1u8, 2, 3] as &[u8]);
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![2, 2];
+            assert_eq!(a.sort_stable_and_dedup().as_slice(), &[2u8] as &[u8]);
+        }
+
+        #[test]
+        fn builder_combinators() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![3];
+            let a = a.pushed(1).appended(smallvec_v1_::smallvec![2]).sorted();
+            assert_eq!(a.as_slice(), &[1u8, 2, 3] as &[u8]);
+            assert_eq!(a.reversed().as_slice(), &[3u8, 2, 1] as &[u8]);
+        }
+
+        #[test]
+        fn max_and_min() {
+            let a: SmallVec1<[(u8, char); 4]> = smallvec1![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+            assert_eq!(a.max_element(), &(2, 'c'));
+            assert_eq!(a.min_element(), &(1, 'b'));
+            assert_eq!(a.max_by_key(|v| v.0), &(2, 'c'));
+            assert_eq!(a.min_by_key(|v| v.0), &(1, 'b'));
+            assert_eq!(a.max_by(|l, r| l.0.cmp(&r.0)), &(2, 'c'));
+            assert_eq!(a.min_by(|l, r| l.0.cmp(&r.0)), &(1, 'b'));
+            assert_eq!(
+                a.reduce_ref(|l, r| if l.1 > r.1 { l } else { r }),
+                &(1, 'd')
+            );
+        }
+
+        #[test]
+        fn min_set_and_max_set() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![3, 1, 3, 1];
+            assert_eq!(a.min_set(), crate::vec1![&1, &1]);
+            assert_eq!(a.max_set(), crate::vec1![&3, &3]);
+        }
+
+        #[test]
+        fn try_map_in_place() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let res = a.try_map_in_place(|x| {
+                if *x > 1 {
+                    return Err(*x);
+                }
+                *x = 0;
+                Ok(())
+            });
+            assert_eq!(res, Err(2));
+            assert_eq!(a.as_slice(), &[0u8, 2, 3] as &[u8]);
+            let res = a.try_replace_in_place(|x| Ok::<_, ()>(x + 1));
+            assert_eq!(res, Ok(()));
+            assert_eq!(a.as_slice(), &[1u8, 3, 4] as &[u8]);
+        }
+
+        #[test]
+        fn scan1() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.scan1(|x| *x, |acc, x| acc + x), crate::vec1![1, 3, 6]);
+        }
+
+        #[test]
+        fn coalesce() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 4, 5, 7];
+            let merged = a.coalesce(|l, r| if r == l + 1 { Ok(r) } else { Err((l, r)) });
+            assert_eq!(merged.as_slice(), &[2u8, 5, 7] as &[u8]);
+        }
+
+        #[test]
+        fn remove_indices() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![0, 1, 2, 3, 4];
+            a.remove_indices(&[0, 3]).unwrap();
+            assert_eq!(a.as_slice(), &[1u8, 2, 4] as &[u8]);
+            assert_eq!(a.remove_indices(&[0, 1, 2]), Err(Size0Error));
+        }
+
+        #[test]
+        fn pairs() {
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 4];
+            assert_eq!(a.pairs().next_back(), Some((&2u8, &4u8)));
+            assert_eq!(a.pairs().len(), 2);
+            let a: SmallVec1<[u8; 4]> = smallvec1![1];
+            assert_eq!(a.pairs().next(), None);
+        }
+
+        #[test]
+        fn get_clamped() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 4];
+            assert_eq!(a.get_clamped(1), &2);
+            assert_eq!(a.get_clamped(3), &4);
+            *a.get_clamped_mut(7) = 5;
+            assert_eq!(a.as_slice(), &[1u8, 2, 5] as &[u8]);
+        }
+
+        #[test]
+        fn ends() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.ends(), (&1, &3));
+            let (first, last) = a.ends_mut();
+            *first = 10;
+            *last.unwrap() = 30;
+            assert_eq!(a.as_slice(), &[10u8, 2, 30] as &[u8]);
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1];
+            assert_eq!(a.ends_mut(), (&mut 1, None));
+        }
+
+        #[test]
+        fn unique() {
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![3, 1, 3, 2, 1];
+            a.unique();
+            assert_eq!(a.as_slice(), &[3u8, 1, 2] as &[u8]);
+            a.unique_by_key(|v| *v % 2);
+            assert_eq!(a.as_slice(), &[3u8, 2] as &[u8]);
+        }
+
         #[test]
         fn resize_with() {
             let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
@@ -977,6 +2027,18 @@ mod tests {
             SmallVec1::<[u8; 4]>::try_from_elem(1u8, 0).unwrap_err();
         }
 
+        #[test]
+        fn non_zero_len_constructors() {
+            let three = NonZeroUsize::new(3).unwrap();
+            let a = SmallVec1::<[u8; 4]>::from_fn(three, |idx| idx as u8);
+            assert_eq!(a.as_slice(), &[0u8, 1, 2] as &[u8]);
+            let a = SmallVec1::<[u8; 2]>::from_elem(7u8, three);
+            assert_eq!(a.as_slice(), &[7u8, 7, 7] as &[u8]);
+            let two = NonZeroUsize::new(2).unwrap();
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            assert_eq!(a.repeat(two).as_slice(), &[1u8, 2, 1, 2] as &[u8]);
+        }
+
         #[test]
         fn split_off_first() {
             let a: SmallVec1<[u8; 4]> = smallvec1![32];
@@ -1021,6 +2083,154 @@ mod tests {
             .is_err());
         }
 
+        #[test]
+        fn builder() {
+            let mut builder = SmallVec1Builder::<[u8; 2]>::new();
+            assert!(builder.is_empty());
+            assert_eq!(builder.clone().finish(), Err(Size0Error));
+            builder.push(1);
+            builder.extend([2, 3]);
+            assert_eq!(builder.len(), 3);
+            let expected: SmallVec1<[u8; 2]> = smallvec1![1, 2, 3];
+            assert_eq!(builder.finish(), Ok(expected));
+            let builder: SmallVec1Builder<[u8; 2]> = (0..0).collect();
+            assert_eq!(builder.finish(), Err(Size0Error));
+        }
+
+        #[cfg(feature = "fake")]
+        mod fake {
+            use super::super::super::*;
+            use fake::{Fake, Faker};
+
+            #[test]
+            fn dummy() {
+                let vec: SmallVec1<[u8; 4]> = Faker.fake();
+                assert!(vec.len() < 10);
+                let vec: SmallVec1<[u8; 4]> = (Faker, 0).fake();
+                assert_eq!(vec.len(), 1);
+            }
+        }
+
+        #[cfg(feature = "smallvec-v1-write")]
+        #[test]
+        fn io_write() {
+            use std::io::Write;
+
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1];
+            assert_eq!(a.write(&[65, 100, 12]).unwrap(), 3);
+            a.write_all(&[7, 8]).unwrap();
+            a.flush().unwrap();
+            assert_eq!(&*a, &[1u8, 65, 100, 12, 7, 8]);
+            assert!(a.spilled());
+        }
+
+        #[cfg(feature = "ufmt")]
+        #[test]
+        fn ufmt_debug() {
+            struct Buf(String);
+
+            impl ufmt::uWrite for Buf {
+                type Error = core::convert::Infallible;
+
+                fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+                    self.0.push_str(s);
+                    Ok(())
+                }
+            }
+
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let mut buf = Buf(String::new());
+            ufmt::uwrite!(buf, "{:?}", a).unwrap();
+            assert_eq!(buf.0, "[1, 2, 3]");
+        }
+
+        #[cfg(feature = "rand")]
+        #[test]
+        fn rand_methods() {
+            use rand::{rngs::SmallRng, SeedableRng};
+            let mut rng = SmallRng::seed_from_u64(42);
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            let chosen = *a.choose_weighted(&mut rng, |v| *v % 2).unwrap();
+            assert!(chosen == 1 || chosen == 3);
+            assert_eq!(a.sample(&mut rng, NonZeroUsize::MIN).len(), 1);
+            let mut shuffled = a.into_shuffled(&mut rng);
+            shuffled.sort();
+            assert_eq!(shuffled.as_slice(), &[1u8, 2, 3] as &[u8]);
+        }
+
+        #[cfg(feature = "arbitrary")]
+        #[test]
+        fn arbitrary_never_empty() {
+            use arbitrary::{Arbitrary, Unstructured};
+            let mut u = Unstructured::new(&[]);
+            let a = SmallVec1::<[u8; 2]>::arbitrary(&mut u).unwrap();
+            assert_eq!(a.as_slice(), &[0u8] as &[u8]);
+        }
+
+        #[cfg(feature = "rayon")]
+        #[test]
+        fn rayon_par_iter() {
+            use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+            let a: SmallVec1<[u32; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.par_iter().sum::<u32>(), 6);
+            assert_eq!(a.par_mapped(|v| v * 2), crate::vec1![2u32, 4, 6]);
+        }
+
+        #[cfg(feature = "speedy")]
+        #[test]
+        fn speedy_roundtrip() {
+            use speedy::{Readable, Writable};
+            let a: SmallVec1<[u16; 4]> = smallvec1![1, 2, 3];
+            let bytes = a.write_to_vec().unwrap();
+            let b = SmallVec1::<[u16; 4]>::read_from_buffer(&bytes).unwrap();
+            assert_eq!(a, b);
+            let bytes = Vec::<u16>::new().write_to_vec().unwrap();
+            assert!(SmallVec1::<[u16; 4]>::read_from_buffer(&bytes).is_err());
+        }
+
+        #[cfg(feature = "musli")]
+        #[test]
+        fn musli_roundtrip() {
+            let a: SmallVec1<[u32; 4]> = smallvec1![1, 2, 3];
+            let bytes = musli_storage::to_vec(&a).unwrap();
+            let got: SmallVec1<[u32; 4]> = musli_storage::from_slice(&bytes).unwrap();
+            assert_eq!(got, a);
+
+            let bytes = musli_storage::to_vec(&Vec::<u32>::new()).unwrap();
+            musli_storage::from_slice::<SmallVec1<[u32; 4]>>(&bytes).unwrap_err();
+        }
+
+        #[cfg(feature = "validator")]
+        #[test]
+        fn validator_length() {
+            use validator::ValidateLength;
+
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.length(), Some(3));
+        }
+
+        #[cfg(feature = "garde")]
+        #[test]
+        fn garde_length() {
+            use garde::rules::length::simple::HasSimpleLength;
+
+            let a: SmallVec1<[u8; 4]> = smallvec1![1, 2, 3];
+            assert_eq!(a.length(), 3);
+        }
+
+        #[cfg(feature = "zeroize")]
+        #[test]
+        fn zeroize() {
+            use zeroize::Zeroize;
+
+            let mut a: SmallVec1<[u8; 2]> = smallvec1![1, 2, 3];
+            a.zeroize();
+            assert_eq!(a.as_slice(), [0]);
+            let mut a: SmallVec1<[u8; 4]> = smallvec1![1, 2];
+            a.zeroize();
+            assert_eq!(a.as_slice(), [0]);
+        }
+
         #[cfg(feature = "serde")]
         mod serde {
             use super::super::super::*;
@@ -1068,5 +2278,14 @@ mod tests {
             assert_eq!(smallvec1_inline![1, 2].capacity(), 2);
             assert_eq!(smallvec1_inline![1, 2,].capacity(), 2);
         }
+
+        #[test]
+        fn smallvec1_inline_in_const() {
+            const A: SmallVec1<[u8; 3]> = smallvec1_inline![1, 2, 3];
+            static B: SmallVec1<[u8; 1]> = smallvec1_inline![4];
+            assert_eq!(&*A, &[1, 2, 3]);
+            assert!(!A.spilled());
+            assert_eq!(&*B, &[4]);
+        }
     }
 }
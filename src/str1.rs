@@ -0,0 +1,193 @@
+//! A non-empty string slice type (`Str1`), the borrowed counterpart of `String1`.
+//!
+//! `Str1` is a unsized wrapper around `str` which guarantees to contain at
+//! least 1 `char`. Like `str` it's always used behind a reference (or box).
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::Str1;
+//!
+//! fn initial(name: &Str1) -> char {
+//!     // no `Option` to handle
+//!     name.first_char()
+//! }
+//!
+//! let name = Str1::try_from_str("Ferris").unwrap();
+//! assert_eq!(initial(name), 'F');
+//! assert!(Str1::try_from_str("").is_err());
+//! ```
+
+use core::{fmt, num::NonZeroUsize, ops::Deref, str::Chars};
+
+use alloc::borrow::ToOwned;
+
+use crate::{Size0Error, String1};
+
+/// A string slice with at least 1 `char`.
+///
+/// Dereferences to `str`.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Str1(str);
+
+impl Str1 {
+    /// Returns a `&Str1` if the string slice is not empty.
+    ///
+    /// # Errors
+    ///
+    /// If the string slice is empty.
+    pub fn try_from_str(string: &str) -> Result<&Self, Size0Error> {
+        if string.is_empty() {
+            Err(Size0Error)
+        } else {
+            // SAFETY: we just checked the string is not empty
+            Ok(unsafe { Self::from_str_unchecked(string) })
+        }
+    }
+
+    /// Creates a `&Str1` without checking that the string slice is not empty.
+    ///
+    /// # Safety
+    ///
+    /// The string slice must not be empty.
+    pub unsafe fn from_str_unchecked(string: &str) -> &Self {
+        debug_assert!(!string.is_empty());
+        // SAFETY: `Str1` is `repr(transparent)` over `str`
+        unsafe { &*(string as *const str as *const Self) }
+    }
+
+    /// Returns the underlying string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the length in bytes as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns the first `char`.
+    pub fn first_char(&self) -> char {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.chars().next().unwrap()
+    }
+
+    /// Returns the last `char`.
+    pub fn last_char(&self) -> char {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.chars().next_back().unwrap()
+    }
+
+    /// Returns the first `char` and an iterator over the remaining (possibly no) `char`s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::Str1;
+    /// let string = Str1::try_from_str("abc").unwrap();
+    /// let (first, rest) = string.chars1();
+    /// assert_eq!(first, 'a');
+    /// assert_eq!(rest.as_str(), "bc");
+    /// ```
+    pub fn chars1(&self) -> (char, Chars<'_>) {
+        let mut chars = self.0.chars();
+        //UNWRAP_SAFE: len is at least 1
+        let first = chars.next().unwrap();
+        (first, chars)
+    }
+
+    /// Copies the string slice into a new `String1`.
+    pub fn to_string1(&self) -> String1 {
+        //UNWRAP_SAFE: len is at least 1
+        String1::try_from(&self.0).unwrap()
+    }
+}
+
+impl Deref for Str1 {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Str1 {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ToOwned for Str1 {
+    type Owned = String1;
+
+    fn to_owned(&self) -> String1 {
+        self.to_string1()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a Str1 {
+    type Error = Size0Error;
+
+    fn try_from(string: &'a str) -> Result<Self, Size0Error> {
+        Str1::try_from_str(string)
+    }
+}
+
+impl<'a> From<&'a Str1> for &'a str {
+    fn from(string: &'a Str1) -> Self {
+        &string.0
+    }
+}
+
+impl fmt::Debug for Str1 {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl fmt::Display for Str1 {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fter)
+    }
+}
+
+impl PartialEq<str> for Str1 {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Str1 {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == **other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn try_from_str() {
+        let string = Str1::try_from_str("ab").unwrap();
+        assert_eq!(string, "ab");
+        assert_eq!(Str1::try_from_str(""), Err(Size0Error));
+        let res: Result<&Str1, _> = "".try_into();
+        assert_eq!(res, Err(Size0Error));
+    }
+
+    #[test]
+    fn chars() {
+        let string = Str1::try_from_str("äyz").unwrap();
+        assert_eq!(string.first_char(), 'ä');
+        assert_eq!(string.last_char(), 'z');
+        assert_eq!(string.len_nonzero().get(), 4);
+        let (first, rest) = string.chars1();
+        assert_eq!(first, 'ä');
+        assert_eq!(rest.collect::<String>(), "yz");
+        assert_eq!(string.to_owned(), "äyz");
+    }
+}
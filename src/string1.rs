@@ -0,0 +1,268 @@
+//! A non-empty string type (`String1`).
+//!
+//! `String1` is a wrapper around `String` which guarantees to contain at
+//! least 1 `char`. Like `Vec1` methods which could remove the last `char`
+//! return a `Result` instead.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::String1;
+//!
+//! let mut name = String1::try_from("vec").unwrap();
+//! name.push('1');
+//! assert_eq!(name, "vec1");
+//! assert_eq!(name.last_char(), '1');
+//! assert!(String1::try_from("").is_err());
+//! ```
+
+use core::{borrow::Borrow, fmt, ops::Deref, str::FromStr};
+
+use alloc::{borrow::ToOwned, string::String};
+
+use crate::{Size0Error, Str1};
+
+/// `String` wrapper which guarantees to have at least 1 `char`.
+///
+/// Dereferences to `&Str1` (and through it to `&str`), functionality
+/// which could make the string empty returns a `Result`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct String1(String);
+
+impl String1 {
+    /// Creates a new `String1` containing just `first`.
+    pub fn new(first: char) -> Self {
+        let mut string = String::new();
+        string.push(first);
+        String1(string)
+    }
+
+    /// Tries to create a `String1` from a `String`.
+    ///
+    /// # Errors
+    ///
+    /// If the string is empty.
+    pub fn try_from_string(string: String) -> Result<Self, Size0Error> {
+        if string.is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(String1(string))
+        }
+    }
+
+    /// Returns the underlying `String`.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Returns the content as `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Appends a `char` to the end of the string.
+    pub fn push(&mut self, ch: char) {
+        self.0.push(ch)
+    }
+
+    /// Appends a string slice to the end of the string.
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string)
+    }
+
+    /// Removes the last `char`, if there is more than one `char` in the string.
+    ///
+    /// # Errors
+    ///
+    /// If the string only contains one `char` an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    pub fn pop(&mut self) -> Result<char, Size0Error> {
+        if self.0.chars().nth(1).is_some() {
+            //UNWRAP_SAFE: there are at least 2 chars
+            Ok(self.0.pop().unwrap())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Calls `truncate` on the inner string if `new_len` is not 0.
+    ///
+    /// # Errors
+    ///
+    /// If `new_len` is 0 an error is returned as the
+    /// length >= 1 constraint must be uphold.
+    ///
+    /// # Panics
+    ///
+    /// Like [`String::truncate()`] if `new_len` does not lie on a `char` boundary.
+    pub fn truncate(&mut self, new_len: usize) -> Result<(), Size0Error> {
+        if new_len > 0 {
+            self.0.truncate(new_len);
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    /// Returns the content as `&Str1`.
+    pub fn as_str1(&self) -> &Str1 {
+        // SAFETY: len is at least 1
+        unsafe { Str1::from_str_unchecked(&self.0) }
+    }
+}
+
+impl Deref for String1 {
+    type Target = Str1;
+
+    fn deref(&self) -> &Str1 {
+        self.as_str1()
+    }
+}
+
+impl AsRef<Str1> for String1 {
+    fn as_ref(&self) -> &Str1 {
+        self.as_str1()
+    }
+}
+
+impl Borrow<Str1> for String1 {
+    fn borrow(&self) -> &Str1 {
+        self.as_str1()
+    }
+}
+
+impl AsRef<str> for String1 {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for String1 {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for String1 {
+    type Error = Size0Error;
+
+    fn try_from(string: String) -> Result<Self, Size0Error> {
+        String1::try_from_string(string)
+    }
+}
+
+impl TryFrom<&str> for String1 {
+    type Error = Size0Error;
+
+    fn try_from(string: &str) -> Result<Self, Size0Error> {
+        String1::try_from_string(string.to_owned())
+    }
+}
+
+impl FromStr for String1 {
+    type Err = Size0Error;
+
+    fn from_str(string: &str) -> Result<Self, Size0Error> {
+        String1::try_from(string)
+    }
+}
+
+impl From<String1> for String {
+    fn from(string: String1) -> Self {
+        string.0
+    }
+}
+
+impl From<char> for String1 {
+    fn from(ch: char) -> Self {
+        String1::new(ch)
+    }
+}
+
+impl Extend<char> for String1 {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a> Extend<&'a str> for String1 {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl fmt::Write for String1 {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.0.push_str(string);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for String1 {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl fmt::Display for String1 {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fter)
+    }
+}
+
+impl PartialEq<str> for String1 {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for String1 {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for String1 {
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn try_from() {
+        assert_eq!(String1::try_from("a"), Ok(String1::new('a')));
+        assert_eq!(String1::try_from(String::new()), Err(Size0Error));
+        assert_eq!("".parse::<String1>(), Err(Size0Error));
+        assert_eq!(String::from(String1::from('b')), "b");
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let mut string = String1::new('ä');
+        string.push('b');
+        string.push_str("cd");
+        assert_eq!(string, "äbcd");
+        assert_eq!(string.pop(), Ok('d'));
+        assert_eq!(string.truncate(2), Ok(()));
+        assert_eq!(string, "ä");
+        assert_eq!(string.pop(), Err(Size0Error));
+        assert_eq!(string.truncate(0), Err(Size0Error));
+        assert_eq!(string.len_nonzero().get(), 2);
+    }
+
+    #[test]
+    fn chars() {
+        let string = String1::try_from("xyz").unwrap();
+        assert_eq!(string.first_char(), 'x');
+        assert_eq!(string.last_char(), 'z');
+        let (first, rest) = string.chars1();
+        assert_eq!(first, 'x');
+        assert_eq!(rest.collect::<String>(), "yz");
+        assert_eq!(string.to_string(), "xyz");
+    }
+}
@@ -0,0 +1,314 @@
+//! Helpers for testing code which uses `Vec1`.
+//!
+//! This module is meant to be used from tests, fuzz targets and property tests
+//! of downstream crates. It provides:
+//!
+//! - [`assert_invariants()`] which panics if a `Vec1` is in a state it should never be in
+//! - [`Op`] and [`Model`] which apply a sequence of operations to a `Vec1` and a
+//!   plain reference `Vec` and assert that both behave the same (including when an
+//!   operation is rejected with a [`Size0Error`]).
+//!
+//! All indices in [`Op`] are reduced into the valid range of the vector at the time the
+//! operation is applied, so any sequence of operations (e.g. one produced by a fuzzer)
+//! can be applied without panicking due to out of bounds indices.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::testing::{Model, Op};
+//!
+//! let mut model = Model::new(1u8);
+//! model.apply_all(vec![
+//!     Op::Push(2),
+//!     Op::Pop,
+//!     Op::Pop,
+//!     Op::Insert(7, 3),
+//!     Op::Drain(0, 2),
+//! ]);
+//! assert_eq!(model.vec1(), &vec1::vec1![1, 3]);
+//! ```
+use core::fmt::Debug;
+
+use alloc::vec::Vec;
+
+use crate::{Size0Error, Vec1};
+
+/// Panics if the given `Vec1` violates any of it's invariants.
+///
+/// Besides `len >= 1` this also checks that the accessors which rely
+/// on that invariant are consistent with the underlying slice.
+#[track_caller]
+pub fn assert_invariants<T>(vec: &Vec1<T>) {
+    let slice = vec.as_slice();
+    assert!(!slice.is_empty(), "Vec1 is empty");
+    assert_eq!(vec.len(), slice.len(), "len mismatch");
+    assert_eq!(vec.len_nonzero().get(), slice.len(), "len_nonzero mismatch");
+    assert!(vec.capacity() >= vec.len(), "capacity is smaller than len");
+    assert!(
+        core::ptr::eq(vec.first(), &slice[0]),
+        "first is not the first element"
+    );
+    assert!(
+        core::ptr::eq(vec.last(), &slice[slice.len() - 1]),
+        "last is not the last element"
+    );
+}
+
+/// A operation which can be applied to a [`Model`].
+///
+/// Indices are reduced into the valid range when the operation is applied,
+/// e.g. `Remove(idx)` removes the element at `idx % len`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Op<T> {
+    /// Calls [`Vec1::push()`].
+    Push(T),
+    /// Calls [`Vec1::pop()`].
+    Pop,
+    /// Calls [`Vec1::insert()`], the index is reduced modulo `len + 1`.
+    Insert(usize, T),
+    /// Calls [`Vec1::remove()`], the index is reduced modulo `len`.
+    Remove(usize),
+    /// Calls [`Vec1::swap_remove()`], the index is reduced modulo `len`.
+    SwapRemove(usize),
+    /// Calls [`Vec1::truncate()`].
+    Truncate(usize),
+    /// Calls [`Vec1::split_off()`], the index is reduced modulo `len + 1`.
+    SplitOff(usize),
+    /// Calls [`Vec1::drain()`] with `start..end`, both are reduced modulo `len + 1`
+    /// and swapped if `start > end`.
+    Drain(usize, usize),
+    /// Calls [`Vec1::splice()`] with `start..end` (reduced like for `Drain`).
+    Splice(usize, usize, Vec<T>),
+    /// Calls [`Extend::extend()`].
+    Extend(Vec<T>),
+    /// Calls [`Vec1::dedup()`].
+    Dedup,
+}
+
+/// Applies [`Op`]s to a `Vec1` and a reference `Vec` asserting both behave the same.
+///
+/// The reference behavior is the one of `Vec` except that any operation which would
+/// leave the vector empty must fail with a [`Size0Error`] without modifying it.
+///
+/// After each operation [`assert_invariants()`] is called on the `Vec1`.
+#[derive(Debug, Clone)]
+pub struct Model<T> {
+    vec1: Vec1<T>,
+    reference: Vec<T>,
+}
+
+impl<T> Model<T>
+where
+    T: Clone + PartialEq + Debug,
+{
+    /// Creates a new model starting with a single element.
+    pub fn new(first: T) -> Self {
+        Self::from_vec1(Vec1::new(first))
+    }
+
+    /// Creates a new model starting with given `Vec1`.
+    pub fn from_vec1(vec1: Vec1<T>) -> Self {
+        assert_invariants(&vec1);
+        let reference = vec1.to_vec();
+        Model { vec1, reference }
+    }
+
+    /// Returns the `Vec1` the operations are applied to.
+    pub fn vec1(&self) -> &Vec1<T> {
+        &self.vec1
+    }
+
+    /// Returns the `Vec1` the operations were applied to.
+    pub fn into_vec1(self) -> Vec1<T> {
+        self.vec1
+    }
+
+    /// Applies all operations in order, see [`Model::apply()`].
+    #[track_caller]
+    pub fn apply_all(&mut self, ops: impl IntoIterator<Item = Op<T>>) {
+        for op in ops {
+            self.apply(op);
+        }
+    }
+
+    /// Applies given operation to the `Vec1` and the reference `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Vec1` behaves differently from the reference, or if
+    /// it violates it's invariants afterwards.
+    #[track_caller]
+    pub fn apply(&mut self, op: Op<T>) {
+        let len = self.reference.len();
+        match op {
+            Op::Push(value) => {
+                self.reference.push(value.clone());
+                self.vec1.push(value);
+            }
+            Op::Pop => {
+                let expected = if len > 1 {
+                    self.reference.pop().ok_or(Size0Error)
+                } else {
+                    Err(Size0Error)
+                };
+                assert_eq!(self.vec1.pop(), expected);
+            }
+            Op::Insert(idx, value) => {
+                let idx = idx % (len + 1);
+                self.reference.insert(idx, value.clone());
+                self.vec1.insert(idx, value);
+            }
+            Op::Remove(idx) => {
+                let idx = idx % len;
+                let expected = if len > 1 {
+                    Ok(self.reference.remove(idx))
+                } else {
+                    Err(Size0Error)
+                };
+                assert_eq!(self.vec1.remove(idx), expected);
+            }
+            Op::SwapRemove(idx) => {
+                let idx = idx % len;
+                let expected = if len > 1 {
+                    Ok(self.reference.swap_remove(idx))
+                } else {
+                    Err(Size0Error)
+                };
+                assert_eq!(self.vec1.swap_remove(idx), expected);
+            }
+            Op::Truncate(new_len) => {
+                let expected = if new_len > 0 {
+                    self.reference.truncate(new_len);
+                    Ok(())
+                } else {
+                    Err(Size0Error)
+                };
+                assert_eq!(self.vec1.truncate(new_len), expected);
+            }
+            Op::SplitOff(at) => {
+                let at = at % (len + 1);
+                let expected = if at > 0 && at < len {
+                    Ok(self.reference.split_off(at))
+                } else {
+                    Err(Size0Error)
+                };
+                let got = self.vec1.split_off(at).map(Vec1::into_vec);
+                assert_eq!(got, expected);
+            }
+            Op::Drain(start, end) => {
+                let (start, end) = reduce_range(start, end, len);
+                let expected = if end - start < len {
+                    Ok(self.reference.drain(start..end).collect::<Vec<_>>())
+                } else {
+                    Err(Size0Error)
+                };
+                let got = self
+                    .vec1
+                    .drain(start..end)
+                    .map(|drain| drain.collect::<Vec<_>>());
+                assert_eq!(got, expected);
+            }
+            Op::Splice(start, end, replace_with) => {
+                let (start, end) = reduce_range(start, end, len);
+                let expected = if end - start < len || !replace_with.is_empty() {
+                    Ok(self
+                        .reference
+                        .splice(start..end, replace_with.iter().cloned())
+                        .collect::<Vec<_>>())
+                } else {
+                    Err(Size0Error)
+                };
+                #[allow(deprecated)]
+                let got = self
+                    .vec1
+                    .splice(start..end, replace_with)
+                    .map(|splice| splice.collect::<Vec<_>>());
+                assert_eq!(got, expected);
+            }
+            Op::Extend(values) => {
+                self.reference.extend(values.iter().cloned());
+                self.vec1.extend(values);
+            }
+            Op::Dedup => {
+                self.reference.dedup();
+                self.vec1.dedup();
+            }
+        }
+        assert_eq!(self.vec1.as_slice(), self.reference.as_slice());
+        assert_invariants(&self.vec1);
+    }
+}
+
+fn reduce_range(start: usize, end: usize, len: usize) -> (usize, usize) {
+    let start = start % (len + 1);
+    let end = end % (len + 1);
+    if start > end {
+        (end, start)
+    } else {
+        (start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+    use proptest::prelude::*;
+    use std::vec;
+
+    fn op_strategy() -> impl Strategy<Value = Op<u8>> {
+        prop_oneof![
+            any::<u8>().prop_map(Op::Push),
+            Just(Op::Pop),
+            (any::<usize>(), any::<u8>()).prop_map(|(idx, v)| Op::Insert(idx, v)),
+            any::<usize>().prop_map(Op::Remove),
+            any::<usize>().prop_map(Op::SwapRemove),
+            (0..10usize).prop_map(Op::Truncate),
+            any::<usize>().prop_map(Op::SplitOff),
+            (any::<usize>(), any::<usize>()).prop_map(|(s, e)| Op::Drain(s, e)),
+            (
+                any::<usize>(),
+                any::<usize>(),
+                proptest::collection::vec(any::<u8>(), 0..3)
+            )
+                .prop_map(|(s, e, v)| Op::Splice(s, e, v)),
+            proptest::collection::vec(any::<u8>(), 0..3).prop_map(Op::Extend),
+            Just(Op::Dedup),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn vec1_matches_reference(
+            first in any::<u8>(),
+            ops in proptest::collection::vec(op_strategy(), 0..50)
+        ) {
+            let mut model = Model::new(first);
+            model.apply_all(ops);
+        }
+    }
+
+    #[test]
+    fn operations_emptying_the_vec_are_rejected() {
+        let mut model = Model::new(1u8);
+        model.apply_all(vec![
+            Op::Pop,
+            Op::Remove(3),
+            Op::SwapRemove(0),
+            Op::Truncate(0),
+            Op::SplitOff(0),
+            Op::SplitOff(1),
+            Op::Drain(0, 1),
+            Op::Splice(1, 0, vec![]),
+        ]);
+        assert_eq!(model.into_vec1(), vec1![1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatch_with_reference_is_detected() {
+        let mut model = Model::new(1u8);
+        model.reference.push(2);
+        model.apply(Op::Dedup);
+    }
+}
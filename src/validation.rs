@@ -0,0 +1,111 @@
+//! Helpers for collecting all errors (instead of just the first one) into a `Vec1`.
+//!
+//! As the errors are returned as `Vec1<E>` the error case is guaranteed to
+//! carry at least one error.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::{validation::partition_results, vec1};
+//!
+//! fn parse_all(inputs: &[&str]) -> Result<Vec<u8>, vec1::Vec1<String>> {
+//!     partition_results(inputs.iter().map(|input| {
+//!         input.parse::<u8>().map_err(|_| format!("invalid number: {input}"))
+//!     }))
+//! }
+//!
+//! assert_eq!(parse_all(&["1", "2"]), Ok(vec![1, 2]));
+//! assert_eq!(
+//!     parse_all(&["1", "x", "300"]),
+//!     Err(vec1!["invalid number: x".to_owned(), "invalid number: 300".to_owned()])
+//! );
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{IntoIterator1, Vec1};
+
+/// Collects all `Ok` values, or all `Err` values if there is at least one.
+///
+/// Unlike collecting into a `Result<Vec<T>, E>` this doesn't stop at the
+/// first error. Once an error was found the `Ok` values are dropped.
+///
+/// # Errors
+///
+/// If any of the results is an `Err`, all errors are returned in order.
+pub fn partition_results<I, T, E>(results: I) -> Result<Vec<T>, Vec1<E>>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    let mut results = results.into_iter();
+    let mut values = Vec::new();
+    for result in results.by_ref() {
+        match result {
+            Ok(value) => values.push(value),
+            Err(err) => return Err(collect_errors(err, results)),
+        }
+    }
+    Ok(values)
+}
+
+/// Like [`partition_results()`] but for a non-empty input, so the `Ok` values are a `Vec1`, too.
+///
+/// # Errors
+///
+/// If any of the results is an `Err`, all errors are returned in order.
+pub fn partition_results1<I, T, E>(results: I) -> Result<Vec1<T>, Vec1<E>>
+where
+    I: IntoIterator1<Item = Result<T, E>>,
+{
+    let (first, rest) = results.into_iter1().split_first();
+    match first {
+        Ok(first) => partition_results(rest).map(|rest| {
+            let mut values = Vec1::with_capacity(first, rest.len() + 1);
+            values.extend(rest);
+            values
+        }),
+        Err(err) => Err(collect_errors(err, rest)),
+    }
+}
+
+fn collect_errors<T, E>(first: E, rest: impl Iterator<Item = Result<T, E>>) -> Vec1<E> {
+    let mut errors = Vec1::new(first);
+    errors.extend(rest.filter_map(Result::err));
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn partition_results_collects_all_errors() {
+        let results: [Result<u8, &str>; 0] = [];
+        assert_eq!(partition_results(results), Ok(Vec::new()));
+        assert_eq!(
+            partition_results([Ok::<_, &str>(1u8), Ok(2)]),
+            Ok(Vec::from([1u8, 2]))
+        );
+        assert_eq!(
+            partition_results([Ok(1u8), Err("a"), Ok(2), Err("b")]),
+            Err(vec1!["a", "b"])
+        );
+    }
+
+    #[test]
+    fn partition_results1_keeps_non_emptiness() {
+        assert_eq!(
+            partition_results1(vec1![Ok::<_, &str>(1u8), Ok(2)]),
+            Ok(vec1![1u8, 2])
+        );
+        assert_eq!(
+            partition_results1(vec1![Err::<u8, _>("a")]),
+            Err(vec1!["a"])
+        );
+        assert_eq!(
+            partition_results1(vec1![Ok(1u8), Err("a"), Err("b")]),
+            Err(vec1!["a", "b"])
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! A vector with a const generic minimum length (`VecN`).
+//!
+//! `VecN<T, MIN>` generalizes the length >= 1 guarantee of `Vec1` to any
+//! minimum length >= 1, e.g. "at least two points" for a line segment.
+//!
+//! For backwards compatibility `Vec1<T>` is *not* an alias of `VecN<T, 1>`
+//! (see the `FIXME(v2.0)` in the `shared` module), but they can be cheaply
+//! converted into each other.
+//!
+//! # Example
+//!
+//! ```
+//! use vec1::VecN;
+//!
+//! let mut polygon = VecN::from_array([(0, 0), (1, 0), (0, 1)]);
+//! polygon.push((1, 1));
+//! assert_eq!(polygon.pop(), Ok((1, 1)));
+//! // a polygon needs at least three points
+//! assert!(polygon.pop().is_err());
+//! ```
+
+use core::{
+    fmt,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+};
+
+use alloc::vec::{self, Vec};
+
+use crate::{Slice1, Vec1};
+
+/// Error returned by operations which would cause a `VecN` to have less than `MIN` elements.
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct MinLenError;
+
+impl fmt::Display for MinLenError {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fter.write_str("Cannot produce a VecN with a length below its minimum length.")
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl std::error::Error for MinLenError {}
+
+/// `Vec` wrapper which guarantees to have at least `MIN` elements.
+///
+/// Like `Vec1` it dereferences to `&[T]`/`&mut [T]`, methods which could
+/// make the vector shorter than `MIN` return a `Result` and methods like
+/// `first` and `last` are shadowed by methods which don't return a `Option`.
+///
+/// A `VecN<T, 0>` can't be created, trying to do so fails to compile.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VecN<T, const MIN: usize>(Vec<T>);
+
+impl<T, const MIN: usize> VecN<T, MIN> {
+    const MIN_NOT_ZERO: () = assert!(MIN > 0, "VecN requires a minimum length of at least 1");
+
+    /// The minimum length of this vector type.
+    pub const MIN_LEN: usize = MIN;
+
+    /// Creates a new `VecN` from an array of exactly `MIN` elements.
+    pub fn from_array(array: [T; MIN]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::MIN_NOT_ZERO;
+        VecN(Vec::from(array))
+    }
+
+    /// Tries to create a `VecN` from a `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// If the vector has less than `MIN` elements.
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, MinLenError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::MIN_NOT_ZERO;
+        if vec.len() < MIN {
+            Err(MinLenError)
+        } else {
+            Ok(VecN(vec))
+        }
+    }
+
+    /// Turns this `VecN` into a `Vec`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Turns this `VecN` into a `Vec1`.
+    pub fn into_vec1(self) -> Vec1<T> {
+        //UNWRAP_SAFE: len is at least MIN which is at least 1
+        Vec1::try_from_vec(self.0).unwrap()
+    }
+
+    /// Return a reference to the underlying `Vec`.
+    pub fn as_vec(&self) -> &Vec<T> {
+        &self.0
+    }
+
+    /// Returns the elements as slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the elements as mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// Returns the elements as a non-empty `Slice1`.
+    pub fn as_slice1(&self) -> &Slice1<T> {
+        // SAFETY: len is at least MIN which is at least 1
+        unsafe { Slice1::from_slice_unchecked(&self.0) }
+    }
+
+    /// Returns the elements as a mutable non-empty `Slice1`.
+    pub fn as_mut_slice1(&mut self) -> &mut Slice1<T> {
+        // SAFETY: len is at least MIN which is at least 1
+        unsafe { Slice1::from_mut_slice_unchecked(&mut self.0) }
+    }
+
+    /// Returns the length as `NonZeroUsize`.
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        //UNWRAP_SAFE: len is at least MIN which is at least 1
+        NonZeroUsize::new(self.0.len()).unwrap()
+    }
+
+    /// Returns a reference to the first element.
+    pub fn first(&self) -> &T {
+        self.as_slice1().first()
+    }
+
+    /// Returns a mutable reference to the first element.
+    pub fn first_mut(&mut self) -> &mut T {
+        self.as_mut_slice1().first_mut()
+    }
+
+    /// Returns a reference to the last element.
+    pub fn last(&self) -> &T {
+        self.as_slice1().last()
+    }
+
+    /// Returns a mutable reference to the last element.
+    pub fn last_mut(&mut self) -> &mut T {
+        self.as_mut_slice1().last_mut()
+    }
+
+    /// Appends an element to the back.
+    pub fn push(&mut self, value: T) {
+        self.0.push(value)
+    }
+
+    /// Inserts an element at position `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.0.insert(index, value)
+    }
+
+    /// Remove the last element from this vector, if there are more than `MIN` elements in it.
+    ///
+    /// # Errors
+    ///
+    /// If len is `MIN` an error is returned as the
+    /// length >= `MIN` constraint must be uphold.
+    pub fn pop(&mut self) -> Result<T, MinLenError> {
+        if self.0.len() > MIN {
+            //UNWRAP_SAFE: pop on len > MIN can not be none
+            Ok(self.0.pop().unwrap())
+        } else {
+            Err(MinLenError)
+        }
+    }
+
+    /// Calls `remove` on the inner vector if length > `MIN`.
+    ///
+    /// # Errors
+    ///
+    /// If len is `MIN` an error is returned as the
+    /// length >= `MIN` constraint must be uphold.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater or equal then `len`.
+    pub fn remove(&mut self, index: usize) -> Result<T, MinLenError> {
+        if self.0.len() > MIN {
+            Ok(self.0.remove(index))
+        } else {
+            Err(MinLenError)
+        }
+    }
+
+    /// Calls `swap_remove` on the inner vector if length > `MIN`.
+    ///
+    /// # Errors
+    ///
+    /// If len is `MIN` an error is returned as the
+    /// length >= `MIN` constraint must be uphold.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater or equal then `len`.
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, MinLenError> {
+        if self.0.len() > MIN {
+            Ok(self.0.swap_remove(index))
+        } else {
+            Err(MinLenError)
+        }
+    }
+
+    /// Calls `truncate` on the inner vector if `len` is at least `MIN`.
+    ///
+    /// # Errors
+    ///
+    /// If `len` is less than `MIN` an error is returned as the
+    /// length >= `MIN` constraint must be uphold.
+    pub fn truncate(&mut self, len: usize) -> Result<(), MinLenError> {
+        if len >= MIN {
+            self.0.truncate(len);
+            Ok(())
+        } else {
+            Err(MinLenError)
+        }
+    }
+}
+
+impl<T, const MIN: usize> Deref for VecN<T, MIN> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize> DerefMut for VecN<T, MIN> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const MIN: usize> AsRef<[T]> for VecN<T, MIN> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize> AsMut<[T]> for VecN<T, MIN> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const MIN: usize> From<[T; MIN]> for VecN<T, MIN> {
+    fn from(array: [T; MIN]) -> Self {
+        VecN::from_array(array)
+    }
+}
+
+impl<T, const MIN: usize> TryFrom<Vec<T>> for VecN<T, MIN> {
+    type Error = MinLenError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, MinLenError> {
+        VecN::try_from_vec(vec)
+    }
+}
+
+impl<T, const MIN: usize> TryFrom<Vec1<T>> for VecN<T, MIN> {
+    type Error = MinLenError;
+
+    fn try_from(vec: Vec1<T>) -> Result<Self, MinLenError> {
+        VecN::try_from_vec(vec.into_vec())
+    }
+}
+
+impl<T, const MIN: usize> From<VecN<T, MIN>> for Vec<T> {
+    fn from(vec: VecN<T, MIN>) -> Self {
+        vec.0
+    }
+}
+
+impl<T, const MIN: usize> From<VecN<T, MIN>> for Vec1<T> {
+    fn from(vec: VecN<T, MIN>) -> Self {
+        vec.into_vec1()
+    }
+}
+
+impl<T, const MIN: usize> Extend<T> for VecN<T, MIN> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T, const MIN: usize> IntoIterator for VecN<T, MIN> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const MIN: usize> IntoIterator for &'a VecN<T, MIN> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const MIN: usize> IntoIterator for &'a mut VecN<T, MIN> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const MIN: usize> fmt::Debug for VecN<T, MIN>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fter)
+    }
+}
+
+impl<A, B, const MIN: usize> PartialEq<[B]> for VecN<A, MIN>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<A, B, const MIN: usize, const N: usize> PartialEq<[B; N]> for VecN<A, MIN>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec1;
+
+    #[test]
+    fn try_from_vec() {
+        assert_eq!(
+            VecN::<u8, 2>::try_from_vec(Vec::from([1, 2])),
+            Ok(VecN::from_array([1u8, 2]))
+        );
+        assert_eq!(
+            VecN::<u8, 2>::try_from_vec(Vec::from([1])),
+            Err(MinLenError)
+        );
+        assert_eq!(VecN::<u8, 2>::try_from(vec1![1u8]), Err(MinLenError));
+        assert_eq!(VecN::<u8, 2>::MIN_LEN, 2);
+    }
+
+    #[test]
+    fn push_and_remove() {
+        let mut vec = VecN::from([1u8, 2]);
+        vec.push(3);
+        vec.insert(0, 0);
+        assert_eq!(vec, [0u8, 1, 2, 3]);
+        assert_eq!(vec.swap_remove(0), Ok(0));
+        assert_eq!(vec.remove(0), Ok(3));
+        assert_eq!(vec.remove(0), Err(MinLenError));
+        assert_eq!(vec.pop(), Err(MinLenError));
+        assert_eq!(vec.truncate(1), Err(MinLenError));
+        assert_eq!(vec.truncate(2), Ok(()));
+        assert_eq!((vec.first(), vec.last()), (&1, &2));
+        assert_eq!(Vec1::from(vec), vec1![1u8, 2]);
+    }
+}